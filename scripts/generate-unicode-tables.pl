@@ -0,0 +1,136 @@
+#!/usr/bin/env perl
+# Generates the tables in src/unicode_tables from the Unicode Character Database that ships
+# with Perl, so building the crate never needs network access. Run from the repository root:
+#
+#   perl scripts/generate-unicode-tables.pl && cargo fmt
+use strict;
+use warnings;
+use Unicode::UCD qw(all_casefolds prop_aliases prop_invlist prop_value_aliases prop_values);
+
+my $header = "// Generated by scripts/generate-unicode-tables.pl from Unicode " . Unicode::UCD::UnicodeVersion() . ". Do not edit.\n";
+
+sub char_literal {
+    return sprintf("'\\u{%X}'", shift);
+}
+
+sub string_literal {
+    return '"' . join('', map { sprintf("\\u{%X}", $_) } @_) . '"';
+}
+
+# the ranges of an inversion list, without the surrogates that a Rust char can't hold
+sub ranges {
+    my @list = prop_invlist(shift);
+    push @list, 0x110000 if @list % 2;
+    my @ranges;
+    while (my ($from, $to) = splice(@list, 0, 2)) {
+        $to--;
+        push @ranges, [$from, $to] if $from < 0xD800 || $from > 0xDFFF;
+        if ($from < 0xD800 && $to > 0xDFFF) {
+            $ranges[-1][1] = 0xD7FF;
+            push @ranges, [0xE000, $to];
+        }
+    }
+    return @ranges;
+}
+
+# names are compared ignoring case, spaces, '_' and '-', as in UAX44-LM3
+sub normalize {
+    my $name = lc(shift);
+    $name =~ s/[ _-]//g;
+    return $name;
+}
+
+# a table of the ranges of each name, and a table mapping the normalized aliases to the names
+sub property_tables {
+    my ($table, $names, $aliases, $queries) = @_;
+    my $out = "pub(crate) const $table: &[(&str, &[(char, char)])] = &[\n";
+    for my $i (sort { $names->[$a] cmp $names->[$b] } 0 .. $#$names) {
+        my $ranges = join(', ', map { '(' . char_literal($_->[0]) . ', ' . char_literal($_->[1]) . ')' } ranges($queries->[$i]));
+        $out .= "    (\"$names->[$i]\", &[$ranges]),\n";
+    }
+    $out .= "];\n\npub(crate) const ${table}_ALIASES: &[(&str, &str)] = &[\n";
+    $out .= "    (\"$_\", \"$aliases->{$_}\"),\n" for sort keys %$aliases;
+    $out .= "];\n";
+    return $out;
+}
+
+sub write_file {
+    my ($path, $contents) = @_;
+    open(my $fh, '>', $path) or die "can't write $path: $!";
+    print $fh $header, "\n", $contents;
+    close($fh);
+}
+
+sub case_folding {
+    my $folds = all_casefolds();
+    my (%simple, %full, %classes);
+    for my $cp (keys %$folds) {
+        my $fold = $folds->{$cp};
+        if ($fold->{simple} ne '') {
+            my $target = hex($fold->{simple});
+            $simple{$cp} = $target;
+            push @{ $classes{$target} }, $cp;
+        }
+        my @full = map { hex } split(' ', $fold->{full});
+        $full{$cp} = \@full if @full > 1;
+    }
+
+    # every char that simple case folding treats as equal, e.g. 'K', 'k' and the Kelvin sign
+    my %orbits;
+    for my $target (keys %classes) {
+        my @class = sort { $a <=> $b } ($target, @{ $classes{$target} });
+        for my $cp (@class) {
+            $orbits{$cp} = [grep { $_ != $cp } @class];
+        }
+    }
+
+    my $out = "pub(crate) const SIMPLE_CASE_FOLDING: &[(char, char)] = &[\n";
+    $out .= "    (" . char_literal($_) . ", " . char_literal($simple{$_}) . "),\n" for sort { $a <=> $b } keys %simple;
+    $out .= "];\n\npub(crate) const FULL_CASE_FOLDING: &[(char, &str)] = &[\n";
+    $out .= "    (" . char_literal($_) . ", " . string_literal(@{ $full{$_} }) . "),\n" for sort { $a <=> $b } keys %full;
+    $out .= "];\n\npub(crate) const CASE_ORBITS: &[(char, &[char])] = &[\n";
+    for my $cp (sort { $a <=> $b } keys %orbits) {
+        $out .= "    (" . char_literal($cp) . ", &[" . join(', ', map { char_literal($_) } @{ $orbits{$cp} }) . "]),\n";
+    }
+    $out .= "];\n";
+    write_file('src/unicode_tables/case_folding.rs', $out);
+}
+
+# the two-letter categories only, the one-letter ones are their unions
+sub general_category {
+    my (@names, @queries, %aliases);
+    for my $value (grep { length == 2 && $_ ne 'LC' } prop_values('gc')) {
+        push @names, $value;
+        push @queries, "gc=$value";
+    }
+    for my $value (prop_values('gc')) {
+        $aliases{ normalize($_) } = $value for prop_value_aliases('gc', $value);
+    }
+    write_file('src/unicode_tables/general_category.rs', property_tables('GENERAL_CATEGORY', \@names, \%aliases, \@queries));
+}
+
+sub script {
+    my (@names, @queries, %aliases);
+    for my $value (prop_values('sc')) {
+        # Katakana_Or_Hiragana is only used by Script_Extensions
+        my @aliases = prop_value_aliases('sc', $value) or next;
+        push @names, $aliases[1];
+        push @queries, "sc=$value";
+        $aliases{ normalize($_) } = $aliases[1] for @aliases;
+    }
+    write_file('src/unicode_tables/script.rs', property_tables('SCRIPT', \@names, \%aliases, \@queries));
+}
+
+sub binary_property {
+    my @names = qw(Alphabetic Cased Join_Control Lowercase Math Noncharacter_Code_Point Uppercase White_Space);
+    my %aliases;
+    for my $name (@names) {
+        $aliases{ normalize($_) } = $name for prop_aliases($name);
+    }
+    write_file('src/unicode_tables/binary_property.rs', property_tables('BINARY_PROPERTY', \@names, \%aliases, \@names));
+}
+
+case_folding();
+general_category();
+script();
+binary_property();
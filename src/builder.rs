@@ -0,0 +1,135 @@
+use crate::{Error, ErrorKind, Regex};
+
+#[derive(Clone, Debug)]
+pub(crate) struct Config {
+    pub(crate) repetition_limit: usize,
+    pub(crate) size_limit: usize,
+    pub(crate) nest_limit: usize,
+    pub(crate) case_insensitive: bool,
+    pub(crate) full_case_folding: bool,
+    pub(crate) multi_line: bool,
+    pub(crate) dot_all: bool,
+    pub(crate) crlf: bool,
+    pub(crate) line_terminator: u8,
+    pub(crate) unicode: bool,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            repetition_limit: 1000,
+            size_limit: 100_000,
+            nest_limit: 250,
+            case_insensitive: false,
+            full_case_folding: false,
+            multi_line: false,
+            dot_all: false,
+            crlf: false,
+            line_terminator: b'\n',
+            unicode: true,
+        }
+    }
+}
+
+/// Configures and builds a `Regex`.
+#[derive(Clone, Debug)]
+pub struct RegexBuilder {
+    pattern: String,
+    config: Config,
+}
+
+impl RegexBuilder {
+    pub fn new(pattern: &str) -> RegexBuilder {
+        RegexBuilder {
+            pattern: String::from(pattern),
+            config: Config::default(),
+        }
+    }
+
+    pub fn build(&self) -> Result<Regex, Error> {
+        if !self.config.line_terminator.is_ascii() {
+            let message = format!("line terminator {:#04x} isn't ASCII", self.config.line_terminator);
+            return Err(Error::new(ErrorKind::InvalidLineTerminator, 0, message));
+        }
+        Regex::with_config(&self.pattern, &self.config)
+    }
+
+    /// Makes letters match regardless of case, as if the pattern started with `(?i)`.
+    /// Defaults to false.
+    pub fn case_insensitive(&mut self, yes: bool) -> &mut RegexBuilder {
+        self.config.case_insensitive = yes;
+        self
+    }
+
+    /// Uses full instead of simple Unicode case folding when matching case-insensitively, so a
+    /// char in the pattern may match several chars in the text and the other way around, e.g. `ß`
+    /// and `[ß]` match "SS", `ss` matches "ß", and `(ß) \1` matches "ß SS". Only consecutive
+    /// literal chars are folded together, so `(s)s` and `s+` don't match "ß", and negated groups,
+    /// `\p{..}` and shorthand classes such as `\w` never match a multi-char folding. Defaults to
+    /// false.
+    pub fn full_case_folding(&mut self, yes: bool) -> &mut RegexBuilder {
+        self.config.full_case_folding = yes;
+        self
+    }
+
+    /// Makes `^` and `$` match at the start and end of every line instead of only at the start and
+    /// end of the text, as if the pattern started with `(?m)`. Defaults to false.
+    pub fn multi_line(&mut self, yes: bool) -> &mut RegexBuilder {
+        self.config.multi_line = yes;
+        self
+    }
+
+    /// Makes `.` match any char, including the line terminator, as if the pattern started with
+    /// `(?s)`. Defaults to false.
+    pub fn dot_matches_new_line(&mut self, yes: bool) -> &mut RegexBuilder {
+        self.config.dot_all = yes;
+        self
+    }
+
+    /// Makes lines end at `\r`, `\n` or `\r\n`, so `.` matches neither `\r` nor `\n` and `$` matches
+    /// before the `\r` of a `\r\n` in multi-line mode, as if the pattern started with `(?R)`. This
+    /// overrides the line terminator. Defaults to false.
+    pub fn crlf(&mut self, yes: bool) -> &mut RegexBuilder {
+        self.config.crlf = yes;
+        self
+    }
+
+    /// Sets the char that `.` doesn't match and at which `^` and `$` match in multi-line mode, e.g.
+    /// `b'\0'` for NUL-separated records. It must be ASCII, since any other byte wouldn't be a char
+    /// on its own, or `build` returns an error. Defaults to `b'\n'`.
+    pub fn line_terminator(&mut self, byte: u8) -> &mut RegexBuilder {
+        self.config.line_terminator = byte;
+        self
+    }
+
+    /// Makes `\d`, `\w` and `\s` match any Unicode digit, word char and white space, and word
+    /// boundaries such as `\b` use the Unicode word chars. With false, they only consider ASCII
+    /// chars, as if the pattern started with `(?-u)`, which is faster on non-ASCII text. Defaults
+    /// to true.
+    pub fn unicode(&mut self, yes: bool) -> &mut RegexBuilder {
+        self.config.unicode = yes;
+        self
+    }
+
+    /// Sets the largest count allowed in a counted repetition such as `a{n,m}`. Defaults to 1000.
+    pub fn repetition_limit(&mut self, limit: usize) -> &mut RegexBuilder {
+        self.config.repetition_limit = limit;
+        self
+    }
+
+    /// Sets the largest number of states the compiled regex may have. Every repetition is compiled
+    /// into its own copy of the sub-expression, so nested repetitions such as `(?:a{1000}){1000}`
+    /// multiply; a pattern that could exceed the limit is rejected by `build`. Defaults to 100,000.
+    pub fn size_limit(&mut self, limit: usize) -> &mut RegexBuilder {
+        self.config.size_limit = limit;
+        self
+    }
+
+    /// Sets how deeply groups may be nested, as parsing and compiling a group recurses into its
+    /// contents; a deeper pattern is rejected by `build` instead of overflowing the stack. Defaults
+    /// to 250.
+    pub fn nest_limit(&mut self, limit: usize) -> &mut RegexBuilder {
+        self.config.nest_limit = limit;
+        self
+    }
+}
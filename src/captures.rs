@@ -0,0 +1,144 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Index;
+use std::sync::Arc;
+
+use crate::matcher::Matcher;
+use crate::matches::Match;
+
+/// The groups captured by a single match. Group 0 is always the whole match.
+#[derive(Clone)]
+pub struct Captures<'t> {
+    text: &'t str,
+    locations: Vec<Option<(usize, usize)>>,
+    group_names: Arc<HashMap<String, usize>>,
+}
+
+impl<'t> Captures<'t> {
+    pub(crate) fn new(text: &'t str, locations: Vec<Option<(usize, usize)>>, group_names: Arc<HashMap<String, usize>>) -> Captures<'t> {
+        Captures {
+            text,
+            locations,
+            group_names,
+        }
+    }
+
+    /// Returns the match for group `i`, or `None` if the group didn't participate in the match.
+    pub fn get(&self, i: usize) -> Option<Match<'t>> {
+        self.locations
+            .get(i)
+            .copied()
+            .flatten()
+            .map(|(start, end)| Match::new(self.text, start, end))
+    }
+
+    /// Returns the match for the group named `name`, or `None` if there is no such group or it
+    /// didn't participate in the match.
+    pub fn name(&self, name: &str) -> Option<Match<'t>> {
+        self.group_names.get(name).and_then(|&i| self.get(i))
+    }
+
+    /// Returns the number of groups, including group 0.
+    pub fn len(&self) -> usize {
+        self.locations.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.locations.is_empty()
+    }
+
+    pub fn iter<'c>(&'c self) -> SubCaptureMatches<'c, 't> {
+        SubCaptureMatches { captures: self, index: 0 }
+    }
+}
+
+// shows the group matches rather than the whole text
+impl fmt::Debug for Captures<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Captures").field(&self.iter().collect::<Vec<_>>()).finish()
+    }
+}
+
+impl Index<usize> for Captures<'_> {
+    type Output = str;
+
+    fn index(&self, i: usize) -> &str {
+        match self.get(i) {
+            Some(m) => m.as_str(),
+            None => panic!("no group at index '{}'", i),
+        }
+    }
+}
+
+impl Index<&str> for Captures<'_> {
+    type Output = str;
+
+    fn index(&self, name: &str) -> &str {
+        match self.name(name) {
+            Some(m) => m.as_str(),
+            None => panic!("no group named '{}'", name),
+        }
+    }
+}
+
+/// An iterator over the groups of a `Captures`, yielding `None` for groups that didn't participate.
+pub struct SubCaptureMatches<'c, 't> {
+    captures: &'c Captures<'t>,
+    index: usize,
+}
+
+impl<'t> Iterator for SubCaptureMatches<'_, 't> {
+    type Item = Option<Match<'t>>;
+
+    fn next(&mut self) -> Option<Option<Match<'t>>> {
+        if self.index >= self.captures.len() {
+            return None;
+        }
+        let m = self.captures.get(self.index);
+        self.index += 1;
+        Some(m)
+    }
+}
+
+/// An iterator over the captures of all non-overlapping matches, created by `Regex::captures_iter`.
+pub struct CaptureMatches<'r, 't> {
+    text: &'t str,
+    matcher: Matcher<'r, 't>,
+    group_names: Arc<HashMap<String, usize>>,
+}
+
+impl<'r, 't> CaptureMatches<'r, 't> {
+    pub(crate) fn new(text: &'t str, matcher: Matcher<'r, 't>, group_names: Arc<HashMap<String, usize>>) -> CaptureMatches<'r, 't> {
+        CaptureMatches { text, matcher, group_names }
+    }
+}
+
+impl<'t> Iterator for CaptureMatches<'_, 't> {
+    type Item = Captures<'t>;
+
+    fn next(&mut self) -> Option<Captures<'t>> {
+        self.matcher
+            .captures()
+            .map(|locations| Captures::new(self.text, locations, self.group_names.clone()))
+    }
+}
+
+/// An iterator over the names of all groups in a regex, created by `Regex::capture_names`.
+/// Yields `None` for unnamed groups, including group 0.
+pub struct CaptureNames<'r> {
+    iter: std::slice::Iter<'r, Option<String>>,
+}
+
+impl<'r> CaptureNames<'r> {
+    pub(crate) fn new(names: &'r [Option<String>]) -> CaptureNames<'r> {
+        CaptureNames { iter: names.iter() }
+    }
+}
+
+impl<'r> Iterator for CaptureNames<'r> {
+    type Item = Option<&'r str>;
+
+    fn next(&mut self) -> Option<Option<&'r str>> {
+        self.iter.next().map(|name| name.as_deref())
+    }
+}
@@ -1,408 +1,513 @@
+use crate::builder::Config;
+use crate::error::{Error, ErrorKind};
 use crate::parser::*;
-use std::collections::{HashMap, HashSet};
-use std::fmt;
-use std::sync::atomic::{AtomicUsize, Ordering};
-use std::{cell::RefCell, rc::Rc};
-
-static COUNTER: AtomicUsize = AtomicUsize::new(1);
-
-pub(crate) struct FSM {
-    pub(crate) start: StateRef,
-    pub(crate) end: StateRef,
+use crate::program::{Assertion, Class, LineTerminator, Program, State, StateId};
+use crate::unicode;
+use std::sync::Arc;
+
+// A piece of the program being compiled. Its end is always an empty split, to which the
+// fragment that follows gets attached.
+#[derive(Clone, Copy)]
+struct Fragment {
+    start: StateId,
+    end: StateId,
 }
 
-impl FSM {
-    fn new(condition: Condition) -> FSM {
-        let start = State::new();
-        let end = State::new();
-        start.borrow_mut().transitions.push(Transition::new(condition, end.clone()));
-        FSM { start, end }
-    }
-
-    pub(crate) fn get_all_states(&self) -> Vec<StateRef> {
-        let mut visited = HashSet::new();
-        let mut states = Vec::new();
-        self.collect_states(Rc::clone(&self.start), &mut visited, &mut states);
-        states
-    }
-
-    fn collect_states(&self, state_ref: StateRef, visited: &mut HashSet<usize>, states: &mut Vec<StateRef>) {
-        let state = state_ref.borrow();
-        if visited.insert(state.id) {
-            states.push(Rc::clone(&state_ref));
-            for transition in &state.transitions {
-                self.collect_states(Rc::clone(&transition.target), visited, states);
-            }
-        }
-    }
+// the flags in effect at the unit being compiled
+#[derive(Clone, Copy)]
+struct Options {
+    case_insensitive: bool,
+    multi_line: bool,
+    dot_all: bool,
+    crlf: bool,
+    unicode: bool,
 }
 
-impl fmt::Display for FSM {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        writeln!(f, "FSM: start {} end {}", self.start.borrow().id, self.end.borrow().id)?;
-        for state in self.get_all_states() {
-            for transition in &state.borrow().transitions {
-                writeln!(
-                    f,
-                    "{} --- {} --> {}",
-                    state.borrow().id,
-                    transition.condition.name,
-                    transition.target.borrow().id
-                )?;
-            }
+impl Options {
+    fn apply(&mut self, flags: &Flags) {
+        if let Some(case_insensitive) = flags.case_insensitive {
+            self.case_insensitive = case_insensitive;
+        }
+        if let Some(multi_line) = flags.multi_line {
+            self.multi_line = multi_line;
+        }
+        if let Some(dot_all) = flags.dot_all {
+            self.dot_all = dot_all;
+        }
+        if let Some(crlf) = flags.crlf {
+            self.crlf = crlf;
+        }
+        if let Some(unicode) = flags.unicode {
+            self.unicode = unicode;
         }
-        Ok(())
     }
 }
 
-pub(crate) struct State {
-    pub(crate) id: usize,
-    pub(crate) transitions: Vec<Transition>,
+pub(crate) struct Compiler {
+    states: Vec<State>,
+    options: Options,
+    full_case_folding: bool,
+    line_terminator: char,
+    requires_backtracking: bool,
+    capture_slots: usize, // loop slots are numbered after the capture slots
+    loops: usize,
 }
 
-pub(crate) type StateRef = Rc<RefCell<State>>;
+impl Compiler {
+    pub(crate) fn compile(ast: &Unit, config: &Config) -> Result<Program, Error> {
+        // nested repetitions multiply, so the size is checked before anything is allocated
+        if size(ast) > config.size_limit {
+            return Err(Error::new(
+                ErrorKind::SizeLimitExceeded,
+                0,
+                format!("compiled regex exceeds the size limit of {} states", config.size_limit),
+            ));
+        }
 
-impl State {
-    fn new() -> StateRef {
-        Rc::new(RefCell::new(State {
-            id: COUNTER.fetch_add(1, Ordering::Relaxed),
-            transitions: Vec::new(),
-        }))
-    }
-}
+        let mut capture_names = vec![None];
+        collect_capture_names(ast, &mut capture_names);
 
-pub(crate) struct Transition {
-    pub(crate) condition: Condition,
-    pub(crate) target: StateRef,
-}
+        let mut compiler = Compiler {
+            states: Vec::new(),
+            options: Options {
+                case_insensitive: config.case_insensitive,
+                multi_line: config.multi_line,
+                dot_all: config.dot_all,
+                crlf: config.crlf,
+                unicode: config.unicode,
+            },
+            full_case_folding: config.full_case_folding,
+            line_terminator: char::from(config.line_terminator),
+            requires_backtracking: false,
+            capture_slots: 2 * capture_names.len(),
+            loops: 0,
+        };
 
-impl Transition {
-    fn new(condition: Condition, target: StateRef) -> Transition {
-        Transition { condition, target }
+        // the whole match is saved as group 0
+        let machines = vec![compiler.save(0), compiler.compile_unit(ast), compiler.save(1)];
+        let fragment = compiler.concat(machines);
+        let end = compiler.push(State::Match);
+        compiler.connect(fragment.end, end);
+        debug_assert!(compiler.states.len() <= size(ast) + 5);
+
+        let group_names = capture_names
+            .iter()
+            .enumerate()
+            .filter_map(|(index, name)| name.clone().map(|name| (name, index)))
+            .collect();
+        Ok(Program {
+            states: compiler.states,
+            start: fragment.start,
+            capture_names,
+            group_names: Arc::new(group_names),
+            loops: compiler.loops,
+            requires_backtracking: compiler.requires_backtracking,
+        })
     }
 
-    fn epsilon(target: StateRef) -> Transition {
-        Transition {
-            condition: Condition::epsilon(),
-            target,
+    fn compile_unit(&mut self, unit: &Unit) -> Fragment {
+        match unit {
+            Unit::ImplicitGroup(children) => {
+                let machines = self.compile_sequence(children);
+                self.concat(machines)
+            }
+            Unit::NonCapturingGroup(children) => self.compile_group(children),
+            Unit::Lookahead { negative, children } => {
+                self.requires_backtracking = true;
+                let body = self.compile_group(children);
+                let end = self.push(State::LookaheadEnd);
+                self.connect(body.end, end);
+                self.single(|next| State::Lookahead {
+                    negative: *negative,
+                    start: body.start,
+                    next,
+                })
+            }
+            Unit::Group { index, children, .. } => {
+                let machines = vec![self.save(2 * index), self.compile_group(children), self.save(2 * index + 1)];
+                self.concat(machines)
+            }
+            Unit::Flags(flags) => {
+                self.options.apply(flags);
+                self.empty()
+            }
+            Unit::Backreference(index) => {
+                self.requires_backtracking = true;
+                let case_insensitive = self.options.case_insensitive;
+                let full_case_folding = self.full_case_folding;
+                self.single(|next| State::Backref {
+                    index: *index,
+                    case_insensitive,
+                    full_case_folding,
+                    next,
+                })
+            }
+            Unit::Alternation(children) => {
+                let machines = children.iter().map(|child| self.compile_unit(child)).collect();
+                self.alternation(machines)
+            }
+            Unit::CharacterClass(c) => match c {
+                CharacterClass::Char(c) => {
+                    if self.options.case_insensitive && self.full_case_folding && unicode::has_full_fold(*c) {
+                        let mut folded = String::new();
+                        unicode::full_fold(*c, &mut folded);
+                        self.folded(folded)
+                    } else {
+                        let case_insensitive = self.options.case_insensitive;
+                        self.single(|next| State::Char {
+                            c: *c,
+                            case_insensitive,
+                            next,
+                        })
+                    }
+                }
+                CharacterClass::Shorthand(shorthand) => self.class(Class::Shorthand {
+                    shorthand: *shorthand,
+                    unicode: self.options.unicode,
+                }),
+                CharacterClass::Property { negative, property } => self.class(Class::Property {
+                    negative: *negative,
+                    property: property.clone(),
+                    case_insensitive: self.options.case_insensitive,
+                }),
+                CharacterClass::Wildcard if self.options.dot_all => self.class(Class::Any),
+                CharacterClass::Wildcard => self.class(Class::AnyExceptLineTerminator(self.line_terminator())),
+                CharacterClass::Group { negative, items } => {
+                    let class = self.class(Class::Group {
+                        negative: *negative,
+                        items: items.clone(),
+                        case_insensitive: self.options.case_insensitive,
+                        unicode: self.options.unicode,
+                    });
+                    if !self.options.case_insensitive || !self.full_case_folding || *negative {
+                        return class;
+                    }
+
+                    // a char of the group may also match several chars, as [ß] matches "SS"
+                    let mut machines = vec![class];
+                    machines.extend(full_folds(items).into_iter().map(|folded| self.folded(folded.to_string())));
+                    self.alternation(machines)
+                }
+            },
+            Unit::Anchor(a) => match a {
+                // without multi-line mode, ^ and $ are \A and \z
+                Anchor::StartOfLine if self.options.multi_line => self.assertion(Assertion::StartOfLine(self.line_terminator())),
+                Anchor::StartOfLine => self.assertion(Assertion::StartOfText),
+                Anchor::EndOfLine if self.options.multi_line => self.assertion(Assertion::EndOfLine(self.line_terminator())),
+                Anchor::EndOfLine => self.assertion(Assertion::EndOfText),
+                Anchor::WordBoundary => self.assertion(Assertion::WordBoundary {
+                    unicode: self.options.unicode,
+                }),
+                Anchor::NotWordBoundary => self.assertion(Assertion::NotWordBoundary {
+                    unicode: self.options.unicode,
+                }),
+                Anchor::StartOfWord => self.assertion(Assertion::StartOfWord {
+                    unicode: self.options.unicode,
+                }),
+                Anchor::EndOfWord => self.assertion(Assertion::EndOfWord {
+                    unicode: self.options.unicode,
+                }),
+                Anchor::StartOfText => self.assertion(Assertion::StartOfText),
+                Anchor::EndOfText => self.assertion(Assertion::EndOfText),
+                Anchor::EndOfTextOrFinalNewline => self.assertion(Assertion::EndOfTextOrFinalNewline),
+                Anchor::PreviousMatchEnd => self.assertion(Assertion::PreviousMatchEnd),
+            },
+            Unit::QuantifiedExpr { expr, quantifier, lazy } => match quantifier {
+                Quantifier::ZeroOrOne => self.repeat(expr, 0, Some(1), *lazy),
+                Quantifier::ZeroOrMore => self.zero_or_more(expr, *lazy),
+                Quantifier::OneOrMore => {
+                    if can_be_empty(expr) {
+                        // compiled as xx* so that only the first iteration may match the empty string
+                        self.repeat(expr, 1, None, *lazy)
+                    } else {
+                        let machine = self.compile_unit(expr);
+                        self.one_or_more(machine, *lazy)
+                    }
+                }
+                Quantifier::Exact(n) => self.repeat(expr, *n, Some(*n), *lazy),
+                Quantifier::Range(min, max) => self.repeat(expr, *min, *max, *lazy),
+            },
         }
     }
-}
 
-#[derive(Clone)]
-pub(crate) struct Cursor<'a> {
-    text: &'a str,
-    pub(crate) index: usize,
-    captured_groups: HashMap<usize, &'a str>,
-}
+    // flags set inside a group only last until the end of the group
+    fn compile_group(&mut self, children: &[Unit]) -> Fragment {
+        let options = self.options;
+        let machines = self.compile_sequence(children);
+        let fragment = self.concat(machines);
+        self.options = options;
+        fragment
+    }
 
-impl<'a> Cursor<'a> {
-    pub(crate) fn new(text: &str) -> Cursor {
-        Cursor {
-            text,
-            index: 0,
-            captured_groups: HashMap::new(),
+    // With full case folding, a run of chars is compiled into one state when a char of the text may
+    // match several of them, as "ß" matches ss, and the other units one by one.
+    fn compile_sequence(&mut self, children: &[Unit]) -> Vec<Fragment> {
+        let mut machines = Vec::new();
+        let mut rest = children;
+        while let Some((first, others)) = rest.split_first() {
+            let run = rest
+                .iter()
+                .take_while(|unit| matches!(unit, Unit::CharacterClass(CharacterClass::Char(_))))
+                .count();
+            if run > 1 && self.options.case_insensitive && self.full_case_folding {
+                let mut folded = String::new();
+                for unit in &rest[..run] {
+                    if let Unit::CharacterClass(CharacterClass::Char(c)) = unit {
+                        unicode::full_fold(*c, &mut folded);
+                    }
+                }
+                if unicode::contains_full_fold(&folded) {
+                    machines.push(self.folded(folded));
+                    rest = &rest[run..];
+                    continue;
+                }
+            }
+            machines.push(self.compile_unit(first));
+            rest = others;
         }
+        machines
     }
 
-    pub(crate) fn char(&self) -> Option<char> {
-        self.text.chars().nth(self.index)
+    // x{2,4} is unrolled into xx(x(x)?)? and x{2,} into xxx*
+    fn repeat(&mut self, expr: &Unit, min: usize, max: Option<usize>, lazy: bool) -> Fragment {
+        let mut machines: Vec<Fragment> = (0..min).map(|_| self.compile_unit(expr)).collect();
+        match max {
+            None => machines.push(self.zero_or_more(expr, lazy)),
+            Some(max) => {
+                // the optional copies share a slot, as each one starts after the previous one ended
+                let slot = (min < max && can_be_empty(expr)).then(|| self.loop_slot());
+                let mut optional = None;
+                for _ in min..max {
+                    let mut machine = self.compile_unit(expr);
+                    if let Some(slot) = slot {
+                        machine = self.iteration(slot, machine);
+                    }
+                    let machine = match optional.take() {
+                        Some(rest) => self.concat(vec![machine, rest]),
+                        None => machine,
+                    };
+                    optional = Some(self.zero_or_one(machine, lazy));
+                }
+                machines.extend(optional);
+            }
+        }
+
+        if machines.is_empty() {
+            return self.empty();
+        }
+        self.concat(machines)
     }
 
-    pub(crate) fn is_end(&self) -> bool {
-        self.index >= self.text.len()
+    fn push(&mut self, state: State) -> StateId {
+        self.states.push(state);
+        self.states.len() - 1
     }
 
-    pub(crate) fn advance(&mut self, n: usize) {
-        self.index += n;
+    // adds an empty transition from the end of a fragment
+    fn connect(&mut self, from: StateId, to: StateId) {
+        match &mut self.states[from] {
+            State::Split(targets) => targets.push(to),
+            _ => unreachable!("fragments end with a split"),
+        }
     }
 
-    pub(crate) fn add_captured_group(&mut self, index: usize, from: usize, to: usize) {
-        self.captured_groups.insert(index, self.text.get(from..to).unwrap());
+    // the matchers try transitions in order, so a greedy quantifier prefers another iteration
+    // and a lazy one prefers to skip it
+    fn choice(&mut self, state: StateId, iteration: StateId, skip: StateId, lazy: bool) {
+        let (first, second) = if lazy { (skip, iteration) } else { (iteration, skip) };
+        self.connect(state, first);
+        self.connect(state, second);
     }
-}
 
-pub(crate) struct Condition {
-    name: String,
-    pub(crate) evaluate: Box<dyn Fn(&Cursor) -> ConditionResult>,
-}
+    fn empty(&mut self) -> Fragment {
+        let state = self.push(State::Split(Vec::new()));
+        Fragment { start: state, end: state }
+    }
 
-pub(crate) enum ConditionResult {
-    Accepted(usize),
-    Rejected,
-}
+    // a fragment made of one state followed by the end split
+    fn single(&mut self, state: impl FnOnce(StateId) -> State) -> Fragment {
+        let end = self.push(State::Split(Vec::new()));
+        let start = self.push(state(end));
+        Fragment { start, end }
+    }
 
-impl Condition {
-    fn epsilon() -> Condition {
-        Condition {
-            name: "epsilon".to_string(),
-            evaluate: Box::new(|_| ConditionResult::Accepted(0)),
+    fn line_terminator(&self) -> LineTerminator {
+        match self.options.crlf {
+            true => LineTerminator::Crlf,
+            false => LineTerminator::Char(self.line_terminator),
         }
     }
 
-    fn match_character(c: char, case_insensitive: bool) -> Condition {
-        Condition {
-            name: format!("'{}'", c),
-            evaluate: Box::new(move |cursor: &Cursor| {
-                let ch = match cursor.char() {
-                    Some(c) => c,
-                    None => return ConditionResult::Rejected,
-                };
-                if case_insensitive && ch.eq_ignore_ascii_case(&c) || ch == c {
-                    ConditionResult::Accepted(1)
-                } else {
-                    ConditionResult::Rejected
-                }
-            }),
-        }
+    fn folded(&mut self, folded: String) -> Fragment {
+        self.single(|next| State::Folded { folded, next })
     }
 
-    fn match_digit() -> Condition {
-        Condition {
-            name: "digit".to_string(),
-            evaluate: Box::new(move |cursor: &Cursor| {
-                let ch = match cursor.char() {
-                    Some(c) => c,
-                    None => return ConditionResult::Rejected,
-                };
-                if ch.is_ascii_digit() {
-                    ConditionResult::Accepted(1)
-                } else {
-                    ConditionResult::Rejected
-                }
-            }),
-        }
+    fn class(&mut self, class: Class) -> Fragment {
+        self.single(|next| State::Class { class, next })
     }
 
-    fn match_word() -> Condition {
-        Condition {
-            name: "word".to_string(),
-            evaluate: Box::new(move |cursor: &Cursor| {
-                let ch = match cursor.char() {
-                    Some(c) => c,
-                    None => return ConditionResult::Rejected,
-                };
-                if ch.is_ascii_alphanumeric() || ch == '_' {
-                    ConditionResult::Accepted(1)
-                } else {
-                    ConditionResult::Rejected
-                }
-            }),
-        }
+    fn assertion(&mut self, assertion: Assertion) -> Fragment {
+        self.single(|next| State::Assertion { assertion, next })
     }
 
-    fn match_any() -> Condition {
-        Condition {
-            name: "any".to_string(),
-            evaluate: Box::new(|cursor: &Cursor| {
-                if cursor.is_end() {
-                    ConditionResult::Rejected
-                } else {
-                    ConditionResult::Accepted(1)
-                }
-            }),
-        }
+    fn save(&mut self, slot: usize) -> Fragment {
+        self.single(|next| State::Save { slot, next })
     }
 
-    fn match_start_of_string() -> Condition {
-        Condition {
-            name: "start_of_string".to_string(),
-            evaluate: Box::new(move |cursor: &Cursor| {
-                if cursor.index == 0 {
-                    ConditionResult::Accepted(0)
-                } else {
-                    ConditionResult::Rejected
-                }
-            }),
+    fn alternation(&mut self, machines: Vec<Fragment>) -> Fragment {
+        let start = self.push(State::Split(Vec::new()));
+        let end = self.push(State::Split(Vec::new()));
+
+        for machine in machines {
+            self.connect(start, machine.start);
+            self.connect(machine.end, end);
         }
+
+        Fragment { start, end }
     }
 
-    fn match_end_of_string() -> Condition {
-        Condition {
-            name: "end_of_string".to_string(),
-            evaluate: Box::new(move |cursor: &Cursor| {
-                if cursor.is_end() {
-                    ConditionResult::Accepted(0)
-                } else {
-                    ConditionResult::Rejected
+    fn concat(&mut self, machines: Vec<Fragment>) -> Fragment {
+        machines
+            .into_iter()
+            .reduce(|lhs, rhs| {
+                self.connect(lhs.end, rhs.start);
+                Fragment {
+                    start: lhs.start,
+                    end: rhs.end,
                 }
-            }),
-        }
+            })
+            .unwrap_or_else(|| self.empty())
     }
 
-    fn match_character_group(negative: bool, items: Vec<CharacterGroupItem>, case_insensitive: bool) -> Condition {
-        Condition {
-            name: format!("character_group {}{:?}", if negative { "^" } else { "" }, items),
-            evaluate: Box::new(move |cursor: &Cursor| {
-                let ch = match cursor.char() {
-                    Some(c) => c,
-                    None => return ConditionResult::Rejected,
-                };
-                let mut result = items.iter().any(|item| match item {
-                    CharacterGroupItem::Char(c) => {
-                        if case_insensitive {
-                            c.eq_ignore_ascii_case(&ch)
-                        } else {
-                            c == &ch
-                        }
-                    }
-                    CharacterGroupItem::Digit => ch.is_ascii_digit(),
-                    CharacterGroupItem::Word => ch.is_ascii_alphanumeric() || ch == '_',
-                });
-                if negative {
-                    result = !result
-                }
-                if result {
-                    ConditionResult::Accepted(1)
-                } else {
-                    ConditionResult::Rejected
-                }
-            }),
-        }
+    fn loop_slot(&mut self) -> usize {
+        self.loops += 1;
+        self.capture_slots + self.loops - 1
     }
 
-    fn match_captured_group(index: usize) -> Condition {
-        Condition {
-            name: format!("captured_group[{}]", index),
-            evaluate: Box::new(move |cursor: &Cursor| {
-                let group = match cursor.captured_groups.get(&index) {
-                    Some(g) => g,
-                    None => return ConditionResult::Rejected,
-                };
-
-                if let Some(substring) = cursor.text.get(cursor.index..) {
-                    if substring.starts_with(group) {
-                        return ConditionResult::Accepted(group.len());
-                    }
-                }
-                ConditionResult::Rejected
-            }),
-        }
+    // An iteration of x*, or an optional one of x? or x{n,m} beyond the first n, saves where it
+    // started in slot, and fails unless the text advanced since then, so that an x which matches
+    // the empty string, as in (a*)*, can't repeat forever and only the required iterations of
+    // x{n,m} may be empty.
+    fn iteration(&mut self, slot: usize, machine: Fragment) -> Fragment {
+        let start = self.push(State::Save { slot, next: machine.start });
+        let end = self.push(State::Split(Vec::new()));
+        let progress = self.push(State::Progress { slot, next: end });
+        self.connect(machine.end, progress);
+        Fragment { start, end }
     }
-}
 
-pub(crate) struct CompiledMachine {
-    pub(crate) fsm: FSM,
-    pub(crate) captured_groups: Vec<CapturedGroup>,
-}
+    fn zero_or_more(&mut self, expr: &Unit, lazy: bool) -> Fragment {
+        let machine = self.compile_unit(expr);
+        let start = self.push(State::Split(Vec::new()));
+        let end = self.push(State::Split(Vec::new()));
+
+        // Kleene Star
+        if can_be_empty(expr) {
+            let slot = self.loop_slot();
+            let iteration = self.iteration(slot, machine);
+            self.choice(start, iteration.start, end, lazy);
+            self.choice(iteration.end, iteration.start, end, lazy);
+        } else {
+            self.choice(start, machine.start, end, lazy);
+            self.choice(machine.end, machine.start, end, lazy);
+        }
 
-pub(crate) struct CapturedGroup {
-    pub(crate) index: usize,
-    pub(crate) start: StateRef,
-    pub(crate) end: StateRef,
-}
+        Fragment { start, end }
+    }
 
-pub(crate) struct Compiler {
-    captured_groups: Vec<CapturedGroup>,
-    match_case_insensitive: bool,
-}
+    fn one_or_more(&mut self, machine: Fragment, lazy: bool) -> Fragment {
+        let end = self.push(State::Split(Vec::new()));
 
-impl Compiler {
-    pub(crate) fn compile(ast: &Unit) -> CompiledMachine {
-        let mut compiler = Compiler {
-            captured_groups: Vec::new(),
-            match_case_insensitive: true,
-        };
+        self.choice(machine.end, machine.start, end, lazy);
 
-        CompiledMachine {
-            fsm: compiler.compile_unit(ast),
-            captured_groups: compiler.captured_groups,
-        }
+        Fragment { start: machine.start, end }
     }
 
-    fn compile_unit(&mut self, unit: &Unit) -> FSM {
-        match unit {
-            Unit::ImplicitGroup(children) => concat(children.iter().map(|child| self.compile_unit(child)).collect()),
-            Unit::Group { index, children } => {
-                let fsm = concat(children.iter().map(|child| self.compile_unit(child)).collect());
-                let group = CapturedGroup {
-                    index: *index,
-                    start: fsm.start.clone(),
-                    end: fsm.end.clone(),
-                };
-                self.captured_groups.push(group);
-                fsm
-            }
-            Unit::Backreference(index) => FSM::new(Condition::match_captured_group(*index)),
-            Unit::Alternation(children) => alternation(children.iter().map(|child| self.compile_unit(child)).collect()),
-            Unit::CharacterClass(c) => match c {
-                CharacterClass::Char(c) => FSM::new(Condition::match_character(*c, self.match_case_insensitive)),
-                CharacterClass::Digit => FSM::new(Condition::match_digit()),
-                CharacterClass::Word => FSM::new(Condition::match_word()),
-                CharacterClass::Wildcard => FSM::new(Condition::match_any()),
-                CharacterClass::Group { negative, items } => {
-                    FSM::new(Condition::match_character_group(*negative, items.clone(), self.match_case_insensitive))
-                }
-            },
-            Unit::Anchor(a) => match a {
-                Anchor::StartOfString => FSM::new(Condition::match_start_of_string()),
-                Anchor::EndOfString => FSM::new(Condition::match_end_of_string()),
-            },
-            Unit::QuantifiedExpr { expr, quantifier } => match quantifier {
-                Quantifier::ZeroOrOne => zero_or_one(self.compile_unit(expr)),
-                Quantifier::ZeroOrMore => zero_or_more(self.compile_unit(expr)),
-                Quantifier::OneOrMore => one_or_more(self.compile_unit(expr)),
-                _ => panic!("not implemented: {:?}", quantifier),
-            },
-        }
-    }
-}
+    fn zero_or_one(&mut self, machine: Fragment, lazy: bool) -> Fragment {
+        let start = self.push(State::Split(Vec::new()));
+        let end = self.push(State::Split(Vec::new()));
 
-fn alternation(machines: Vec<FSM>) -> FSM {
-    let start = State::new();
-    let end = State::new();
+        self.choice(start, machine.start, end, lazy);
+        self.connect(machine.end, end);
 
-    for machine in machines {
-        start.borrow_mut().transitions.push(Transition::epsilon(machine.start));
-        machine.end.borrow_mut().transitions.push(Transition::epsilon(end.clone()));
+        Fragment { start, end }
     }
-
-    FSM { start, end }
 }
 
-fn concat(machines: Vec<FSM>) -> FSM {
-    fn concat_pair(lhs: FSM, rhs: FSM) -> FSM {
-        lhs.end.borrow_mut().transitions.push(Transition::epsilon(rhs.start.clone()));
-        FSM {
-            start: lhs.start,
-            end: rhs.end,
+// an upper bound of the number of states the unit compiles into, not counting the 5 states around
+// the whole regex
+fn size(unit: &Unit) -> usize {
+    let sum = |children: &[Unit]| children.iter().map(size).fold(1, usize::saturating_add);
+    match unit {
+        Unit::ImplicitGroup(children) | Unit::NonCapturingGroup(children) => sum(children),
+        Unit::Group { children, .. } => sum(children).saturating_add(4),
+        Unit::Lookahead { children, .. } => sum(children).saturating_add(3),
+        Unit::Alternation(children) => sum(children).saturating_add(2),
+        // with full case folding, a group is an alternation of the group and its multi-char folds
+        Unit::CharacterClass(CharacterClass::Group { items, .. }) => 2 * full_folds(items).len() + 4,
+        Unit::CharacterClass(_) | Unit::Anchor(_) | Unit::Backreference(_) => 2,
+        Unit::Flags(_) => 1,
+        Unit::QuantifiedExpr { expr, quantifier, .. } => {
+            let size = size(expr);
+            // zero_or_more adds 5 states, zero_or_one and iteration 5 per optional copy and repeat 1
+            let (copies, extra) = match quantifier {
+                Quantifier::ZeroOrOne => (1, 5),
+                Quantifier::ZeroOrMore => (1, 5),
+                Quantifier::OneOrMore => (2, 6),
+                Quantifier::Exact(n) => (*n, 1),
+                Quantifier::Range(min, None) => (min + 1, 6),
+                Quantifier::Range(min, Some(max)) => (*max, (max - min).saturating_mul(5).saturating_add(1)),
+            };
+            size.saturating_mul(copies).saturating_add(extra)
         }
     }
-
-    machines.into_iter().reduce(concat_pair).unwrap()
 }
 
-fn zero_or_more(machine: FSM) -> FSM {
-    let start = State::new();
-    let end = State::new();
-
-    // Kleene Star
-    start.borrow_mut().transitions.push(Transition::epsilon(machine.start.clone()));
-    start.borrow_mut().transitions.push(Transition::epsilon(end.clone()));
-    machine.end.borrow_mut().transitions.push(Transition::epsilon(end.clone()));
-    machine.end.borrow_mut().transitions.push(Transition::epsilon(machine.start.clone()));
-
-    FSM { start, end }
+// the full case foldings of the chars of a group that are several chars long
+fn full_folds(items: &[CharacterGroupItem]) -> Vec<&'static str> {
+    let mut folds: Vec<&str> = items
+        .iter()
+        .filter_map(|item| match item {
+            CharacterGroupItem::Char(c) => Some((*c, *c)),
+            CharacterGroupItem::Range(from, to) => Some((*from, *to)),
+            _ => None,
+        })
+        .flat_map(|(from, to)| unicode::full_folds_in(from, to))
+        .collect();
+    folds.sort_unstable();
+    folds.dedup();
+    folds
 }
 
-fn one_or_more(machine: FSM) -> FSM {
-    let start = State::new();
-    let end = State::new();
-
-    start.borrow_mut().transitions.push(Transition::epsilon(machine.start.clone()));
-    machine.end.borrow_mut().transitions.push(Transition::epsilon(end.clone()));
-    machine.end.borrow_mut().transitions.push(Transition::epsilon(machine.start.clone()));
-
-    FSM { start, end }
+// whether the unit can match the empty string
+fn can_be_empty(unit: &Unit) -> bool {
+    match unit {
+        Unit::ImplicitGroup(children) | Unit::NonCapturingGroup(children) | Unit::Group { children, .. } => children.iter().all(can_be_empty),
+        Unit::Lookahead { .. } => true,
+        Unit::Alternation(children) => children.iter().any(can_be_empty),
+        Unit::QuantifiedExpr { expr, quantifier, .. } => match quantifier {
+            Quantifier::OneOrMore => can_be_empty(expr),
+            Quantifier::Exact(min) | Quantifier::Range(min, _) => *min == 0 || can_be_empty(expr),
+            Quantifier::ZeroOrOne | Quantifier::ZeroOrMore => true,
+        },
+        Unit::CharacterClass(_) => false,
+        Unit::Anchor(_) | Unit::Backreference(_) | Unit::Flags(_) => true,
+    }
 }
 
-fn zero_or_one(machine: FSM) -> FSM {
-    let start = State::new();
-    let end = State::new();
-
-    start.borrow_mut().transitions.push(Transition::epsilon(machine.start.clone()));
-    start.borrow_mut().transitions.push(Transition::epsilon(end.clone()));
-    machine.end.borrow_mut().transitions.push(Transition::epsilon(end.clone()));
-
-    FSM { start, end }
+fn collect_capture_names(unit: &Unit, names: &mut Vec<Option<String>>) {
+    match unit {
+        Unit::Group { index, name, children } => {
+            if *index >= names.len() {
+                names.resize(index + 1, None);
+            }
+            names[*index] = name.clone();
+            children.iter().for_each(|child| collect_capture_names(child, names));
+        }
+        Unit::ImplicitGroup(children) | Unit::NonCapturingGroup(children) | Unit::Lookahead { children, .. } | Unit::Alternation(children) => {
+            children.iter().for_each(|child| collect_capture_names(child, names));
+        }
+        Unit::QuantifiedExpr { expr, .. } => collect_capture_names(expr, names),
+        Unit::CharacterClass(_) | Unit::Anchor(_) | Unit::Backreference(_) | Unit::Flags(_) => {}
+    }
 }
@@ -0,0 +1,62 @@
+use std::fmt;
+
+/// An error that occurred while parsing a pattern.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Error {
+    kind: ErrorKind,
+    offset: usize,
+    message: String,
+}
+
+/// The kind of syntax error found in a pattern.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    UnexpectedCharacter,     // a)
+    UnclosedGroup,           // (a
+    UnclosedCharacterGroup,  // [a
+    EmptyCharacterGroup,     // []
+    InvalidCharacterRange,   // [z-a]
+    InvalidBackreference,    // (a)\2
+    InvalidGroupModifier,    // (?x)
+    InvalidGroupName,        // (?<1a>)
+    DuplicateGroupName,      // (?<a>)(?<a>)
+    InvalidRepetition,       // a{3,2}
+    RepetitionLimitExceeded, // a{100000}
+    SizeLimitExceeded,       // (?:a{1000}){1000}
+    NestLimitExceeded,       // ((((...))))
+    InvalidFlag,             // (?z)
+    InvalidUnicodeProperty,  // \p{Foo}
+    InvalidLineTerminator,   // line_terminator(0xFF)
+}
+
+impl Error {
+    pub(crate) fn new(kind: ErrorKind, offset: usize, message: impl Into<String>) -> Error {
+        Error {
+            kind,
+            offset,
+            message: message.into(),
+        }
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// The char offset in the pattern where the error was detected.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at offset {}", self.message, self.offset)
+    }
+}
+
+impl std::error::Error for Error {}
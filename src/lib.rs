@@ -1,38 +1,177 @@
+use builder::Config;
 use compiler::Compiler;
-use matcher::Matcher;
+use matcher::{Cache, Matcher};
 use parser::Parser;
+use pool::Pool;
+use program::Program;
+use std::fmt;
+use std::sync::Arc;
 
+pub use builder::RegexBuilder;
+pub use captures::{CaptureMatches, CaptureNames, Captures, SubCaptureMatches};
+pub use error::{Error, ErrorKind};
+pub use matches::{Match, Matches};
+
+mod builder;
+mod captures;
 mod compiler;
+mod error;
 mod matcher;
+mod matches;
 mod parser;
+mod pikevm;
+mod pool;
+mod program;
+mod unicode;
+mod unicode_tables;
 
+/// A compiled regex. It can be shared between threads, and cloning it is cheap.
+#[derive(Clone)]
 pub struct Regex {
-    pub pattern: String,
+    pattern: Arc<str>,
+    program: Arc<Program>,
+    pool: Arc<Pool<Cache>>, // scratch space for the searches
 }
 
 impl Regex {
-    pub fn new(pattern: &str) -> Regex {
-        Regex {
-            pattern: String::from(pattern),
-        }
+    /// Parses and compiles `pattern`, returning an error if it isn't a valid regex.
+    pub fn new(pattern: &str) -> Result<Regex, Error> {
+        RegexBuilder::new(pattern).build()
+    }
+
+    pub(crate) fn with_config(pattern: &str, config: &Config) -> Result<Regex, Error> {
+        let mut parser = Parser::new(pattern, config);
+        let unit = parser.parse()?;
+        let program = Compiler::compile(&unit, config)?;
+        Ok(Regex {
+            pattern: Arc::from(pattern),
+            program: Arc::new(program),
+            pool: Arc::new(Pool::new()),
+        })
+    }
+
+    fn matcher<'r, 't>(&'r self, text: &'t str) -> Matcher<'r, 't> {
+        Matcher::new(&self.program, self.pool.get(), text)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.pattern
     }
 
     pub fn matches(&self, text: &str) -> bool {
-        let mut parser = Parser::new(&self.pattern);
-        let unit = parser.parse().unwrap();
-        let machine = Compiler::compile(&unit);
-        let mut matcher = Matcher::new(machine, text);
+        self.is_match(text)
+    }
+
+    pub fn is_match(&self, text: &str) -> bool {
+        let mut matcher = self.matcher(text);
         matcher.matches()
     }
+
+    /// Returns the leftmost match in `text`.
+    pub fn find<'t>(&self, text: &'t str) -> Option<Match<'t>> {
+        let mut matcher = self.matcher(text);
+        matcher.find().map(|(start, end)| Match::new(text, start, end))
+    }
+
+    /// Returns an iterator over all non-overlapping matches in `text`, from left to right.
+    pub fn find_iter<'r, 't>(&'r self, text: &'t str) -> Matches<'r, 't> {
+        Matches::new(text, self.matcher(text))
+    }
+
+    /// Returns the groups captured by the leftmost match in `text`.
+    pub fn captures<'t>(&self, text: &'t str) -> Option<Captures<'t>> {
+        let mut matcher = self.matcher(text);
+        matcher
+            .captures()
+            .map(|locations| Captures::new(text, locations, self.program.group_names.clone()))
+    }
+
+    /// Returns an iterator over the groups captured by every non-overlapping match in `text`.
+    pub fn captures_iter<'r, 't>(&'r self, text: &'t str) -> CaptureMatches<'r, 't> {
+        CaptureMatches::new(text, self.matcher(text), self.program.group_names.clone())
+    }
+
+    /// Returns the number of groups in the pattern, including the implicit group 0.
+    pub fn captures_len(&self) -> usize {
+        self.program.capture_names.len()
+    }
+
+    /// Returns an iterator over the name of every group in the pattern, in index order.
+    pub fn capture_names(&self) -> CaptureNames<'_> {
+        CaptureNames::new(&self.program.capture_names)
+    }
+
+    /// Returns the end offset of the first match to end in `text`. It may end before
+    /// the match returned by `find`, so this is only useful to know where some match ends.
+    pub fn shortest_match(&self, text: &str) -> Option<usize> {
+        let mut matcher = self.matcher(text);
+        matcher.shortest()
+    }
+}
+
+impl fmt::Debug for Regex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Regex").field(&self.as_str()).finish()
+    }
 }
 
 #[cfg(test)]
+#[allow(clippy::invalid_regex)]
 mod tests {
     use super::*;
 
     fn test(test_cases: &[(&str, &str, bool)]) {
         for (i, test) in test_cases.iter().enumerate() {
-            let result = Regex::new(test.0).matches(test.1);
+            let result = Regex::new(test.0).unwrap().matches(test.1);
+            assert_eq!(result, test.2, "Test case {} failed: ({}, {})", i, test.0, test.1);
+        }
+    }
+
+    #[test]
+    fn syntax_error() {
+        let test_cases = [
+            ("a)", ErrorKind::UnexpectedCharacter, 1),
+            ("(ab", ErrorKind::UnclosedGroup, 3),
+            ("[ab", ErrorKind::UnclosedCharacterGroup, 3),
+            ("[]", ErrorKind::EmptyCharacterGroup, 1),
+            ("[z-a]", ErrorKind::InvalidCharacterRange, 1),
+            ("[ab-\\d]", ErrorKind::InvalidCharacterRange, 2),
+            ("(a)\\2", ErrorKind::InvalidBackreference, 3),
+            ("(?x)", ErrorKind::InvalidGroupModifier, 2),
+            ("(?<1a>x)", ErrorKind::InvalidGroupName, 3),
+            ("(?<a>x)(?P<a>y)", ErrorKind::DuplicateGroupName, 11),
+            ("(?<a>x)\\k<b>", ErrorKind::InvalidBackreference, 7),
+            ("(?<a>x)(?P=b)", ErrorKind::InvalidBackreference, 7),
+            ("a{3,2}", ErrorKind::InvalidRepetition, 1),
+            ("a{1001}", ErrorKind::RepetitionLimitExceeded, 1),
+            ("a{99999999999999999999}", ErrorKind::RepetitionLimitExceeded, 1),
+            ("(?:(?:a{1000}){1000}){1000}", ErrorKind::SizeLimitExceeded, 0),
+            ("(?:a{1000}){1000}", ErrorKind::SizeLimitExceeded, 0),
+            ("(?iz)", ErrorKind::InvalidFlag, 3),
+            ("(?i", ErrorKind::UnclosedGroup, 3),
+            ("a\\p{Foo}", ErrorKind::InvalidUnicodeProperty, 1),
+            ("\\p{Script=Foo}", ErrorKind::InvalidUnicodeProperty, 0),
+            ("\\p{L", ErrorKind::InvalidUnicodeProperty, 4),
+            ("\\p", ErrorKind::InvalidUnicodeProperty, 2),
+        ];
+        for (i, test) in test_cases.iter().enumerate() {
+            let err = Regex::new(test.0).unwrap_err();
+            assert_eq!((err.kind(), err.offset()), (test.1, test.2), "Test case {} failed: {}", i, test.0);
+        }
+
+        let nested = |depth: usize| format!("{}a{}", "(?:".repeat(depth), ")".repeat(depth));
+        let err = Regex::new(&nested(10_000)).unwrap_err();
+        assert_eq!((err.kind(), err.offset()), (ErrorKind::NestLimitExceeded, 750));
+        assert!(Regex::new(&nested(250)).unwrap().is_match("a"));
+        let err = RegexBuilder::new("(a)|((?=b))").nest_limit(1).build().unwrap_err();
+        assert_eq!((err.kind(), err.offset()), (ErrorKind::NestLimitExceeded, 5));
+    }
+
+    type Span = Option<(usize, usize)>;
+
+    fn test_find(test_cases: &[(&str, &str, Span)]) {
+        for (i, test) in test_cases.iter().enumerate() {
+            let result = Regex::new(test.0).unwrap().find(test.1).map(|m| (m.start(), m.end()));
             assert_eq!(result, test.2, "Test case {} failed: ({}, {})", i, test.0, test.1);
         }
     }
@@ -41,6 +180,8 @@ mod tests {
     fn simple() {
         let test_cases = vec![("d", "dog", true), ("f", "dog", false)];
         test(&test_cases);
+
+        assert_eq!(format!("{:?}", Regex::new("a\\d+").unwrap()), "Regex(\"a\\\\d+\")");
     }
 
     #[test]
@@ -62,8 +203,53 @@ mod tests {
             ("^log", "slog", false),
             ("cat$", "cat", true),
             ("cat$", "cats", false),
+            ("a^b", "a^b", false),
+            ("(^a|b)+", "ab", true),
+            ("x|^a", "ba", false),
+            ("^$", "", true),
+            ("^*a", "ba", true),
+            ("^+a", "ba", false),
+            ("a$?", "ab", true),
+            ("a\\b+", "ab", false),
+            ("a\\b+", "a b", true),
+            ("a\\b?b", "ab", true),
+            ("a\\z{2}", "a", true),
+        ];
+        test(&test_cases);
+    }
+
+    #[test]
+    fn multi_line() {
+        let test_cases = [
+            ("^b", "a\nb", false),
+            ("(?m)^b", "a\nb", true),
+            ("a$", "a\nb", false),
+            ("(?m)a$", "a\nb", true),
+            ("(?m)a$", "a\r\nb", false),
+            ("(?mR)a$", "a\r\nb", true),
+            ("(?mR)^b", "a\r\nb", true),
+            ("(?mR)^b", "a\rb", true),
+            ("(?mR)^\\n", "a\r\n", false),
+            ("(?mR)\\r$", "a\r\n", false),
+            ("(?m)^\\n", "a\r\n", false),
+            ("(?m:^b)", "a\nb", true),
+            ("(?m:x)^b", "xa\nb", false),
+            ("(?m)\\Ab", "a\nb", false),
+            ("(?m)a\\z", "a\nb", false),
         ];
         test(&test_cases);
+
+        let lines: Vec<_> = Regex::new("(?m)^\\w+$")
+            .unwrap()
+            .find_iter("one\ntwo\n\nthree")
+            .map(|m| m.as_str())
+            .collect();
+        assert_eq!(lines, ["one", "two", "three"]);
+        let re = RegexBuilder::new("^\\w+$").multi_line(true).crlf(true).build().unwrap();
+        let lines: Vec<_> = re.find_iter("one\r\ntwo\r\n").map(|m| m.as_str()).collect();
+        assert_eq!(lines, ["one", "two"]);
+        let empty: Vec<_> = Regex::new("(?mR)^$").unwrap().find_iter("a\r\n\r\nb").map(|m| m.start()).collect();
+        assert_eq!(empty, [3]);
     }
 
     #[test]
@@ -77,6 +263,185 @@ mod tests {
         test(&test_cases);
     }
 
+    #[test]
+    fn character_range() {
+        let test_cases = [
+            ("^[a-z]+$", "hello", true),
+            ("^[a-z]+$", "Hello", false),
+            ("^[0-9A-F]+$", "1A3F", true),
+            ("^[0-9A-F]+$", "1a3f", false),
+            ("^[a-zA-Z_][a-zA-Z0-9_]*$", "_id42", true),
+            ("^[a-zA-Z_][a-zA-Z0-9_]*$", "42id", false),
+            ("^\\d+[.-]\\d+$", "1.2", true),
+            ("^\\d+[.-]\\d+$", "1-2", true),
+            ("^[-a]+$", "a-a", true),
+            ("^[a-]+$", "-a-", true),
+            ("^[a\\-z]+$", "a-z", true),
+            ("^[a\\-z]+$", "b", false),
+            ("[^a-c]", "abc", false),
+            ("[^a-c]", "abcd", true),
+            ("[α-ω]", "λ", true),
+        ];
+        test(&test_cases);
+
+        let re = RegexBuilder::new("^[a-f]+$").case_insensitive(true).build().unwrap();
+        assert!(re.is_match("CAFE"));
+        assert!(!re.is_match("CAFG"));
+        let re = RegexBuilder::new("^[J-L]$").case_insensitive(true).build().unwrap();
+        assert!(re.is_match("\u{212A}")); // the Kelvin sign folds to 'k'
+    }
+
+    #[test]
+    fn shorthand_classes() {
+        let test_cases = [
+            ("^\\D+$", "abc", true),
+            ("^\\D+$", "a1c", false),
+            ("^\\W+$", "-!?", true),
+            ("^\\W+$", "-a?", false),
+            ("a\\sb", "a b", true),
+            ("a\\sb", "a\tb", true),
+            ("a\\sb", "a\u{B}b", true),
+            ("a\\sb", "asb", false),
+            ("^\\S+$", "a-b", true),
+            ("^\\S+$", "a b", false),
+            ("a\\hb", "a\u{3000}b", true),
+            ("a\\hb", "a\nb", false),
+            ("a\\vb", "a\r\nb", false),
+            ("a\\v+b", "a\r\nb", true),
+            ("^[\\d\\s]+$", "1 2\t3", true),
+            ("^[\\D]+$", "abc1", false),
+            ("^[^\\S\\n]+$", " \t ", true),
+            ("^[^\\S\\n]+$", " \n ", false),
+            ("^[\\W\\d]+$", "1-2", true),
+        ];
+        test(&test_cases);
+
+        let words: Vec<_> = Regex::new("\\S+")
+            .unwrap()
+            .find_iter(" split  these\twords\n")
+            .map(|m| m.as_str())
+            .collect();
+        assert_eq!(words, ["split", "these", "words"]);
+    }
+
+    #[test]
+    fn word_boundary() {
+        let test_cases = [
+            ("\\bcat\\b", "the cat sat", true),
+            ("\\bcat\\b", "cat", true),
+            ("\\bcat\\b", "concatenate", false),
+            ("\\bcat\\b", "cats", false),
+            ("\\Bcat\\B", "concatenate", true),
+            ("\\Bcat\\B", "the cat", false),
+            ("^\\B$", "", true),
+            ("^\\b$", "", false),
+            ("\\b{start}cat", "a cat", true),
+            ("\\b{start}cat", "tomcat", false),
+            ("cat\\b{end}", "cat!", true),
+            ("cat\\b{end}", "cats", false),
+            ("\\b{end}cat", " cat", false),
+            ("\\bna\\b", "naïve", false),
+            ("(?-u)\\bna\\b", "naïve", true),
+            ("\\b日本\\b", "(日本)", true),
+            ("a\\b{", "a{", true),
+        ];
+        test(&test_cases);
+
+        test_find(&[
+            ("\\b\\w+\\b{end}", "  hello world", Some((2, 7))),
+            ("\\B.", "ab", Some((1, 2))),
+            ("\\b", "ab", Some((0, 0))),
+        ]);
+        let result: Vec<_> = Regex::new("\\b").unwrap().find_iter("ab cd").map(|m| m.start()).collect();
+        assert_eq!(result, [0, 2, 3, 5]);
+    }
+
+    #[test]
+    fn string_anchors() {
+        let test_cases = [
+            ("\\Aab", "abab", true),
+            ("b\\Aab", "bab", false),
+            ("ab\\z", "abab", true),
+            ("ab\\z", "ab\n", false),
+            ("ab\\Z", "ab\n", true),
+            ("ab\\Z", "ab", true),
+            ("ab\\Z", "ab\n\n", false),
+            ("ab\\Z\\n", "ab\n", true),
+            ("\\Gab", "ab", true),
+            ("\\Gab", "xab", false),
+        ];
+        test(&test_cases);
+
+        let re = Regex::new("\\G(\\d+|[a-z]+)\\s*").unwrap();
+        let tokens: Vec<_> = re.captures_iter("12 ab 3c!de").map(|caps| caps.get(1).unwrap().as_str()).collect();
+        assert_eq!(tokens, ["12", "ab", "3", "c"]);
+        let result: Vec<_> = Regex::new("\\Ga").unwrap().find_iter("aab").map(|m| m.range()).collect();
+        assert_eq!(result, [0..1, 1..2]);
+        let result: Vec<_> = Regex::new("\\Ga*").unwrap().find_iter("aab").map(|m| m.range()).collect();
+        assert_eq!(result, [0..2, 2..2]);
+        let result: Vec<_> = Regex::new("\\G\\b").unwrap().find_iter("ab").map(|m| m.start()).collect();
+        assert_eq!(result, [0]);
+    }
+
+    #[test]
+    fn unicode_classes() {
+        let test_cases = [
+            ("^\\w+$", "naïve", true),
+            ("^\\w+$", "日本語_1", true),
+            ("^\\w+$", "e\u{301}", true),
+            ("^\\d+$", "٣٤", true),
+            ("^\\s+$", "\u{A0}\u{2028}", true),
+            ("^\\W$", "é", false),
+            ("^[\\w]+$", "naïve", true),
+            ("^(?-u)\\w+$", "naïve", false),
+            ("^(?-u)\\W$", "é", true),
+            ("^(?-u)\\d$", "٣", false),
+            ("^(?-u)\\s$", "\u{A0}", false),
+            ("^(?-u)[\\w]+$", "naïve", false),
+            ("^(?-u:\\w+)\\w$", "abé", true),
+            ("^(?-u:\\w+)\\w$", "aéb", false),
+            ("^(?-u)(?u)\\d$", "٣", true),
+        ];
+        test(&test_cases);
+
+        let re = RegexBuilder::new("^\\w+$").unicode(false).build().unwrap();
+        assert!(re.is_match("naive"));
+        assert!(!re.is_match("naïve"));
+        let re = RegexBuilder::new("^(?u)\\w+$").unicode(false).build().unwrap();
+        assert!(re.is_match("naïve"));
+    }
+
+    #[test]
+    fn unicode_properties() {
+        let test_cases = [
+            ("^\\p{L}+$", "naïve", true),
+            ("^\\p{L}+$", "日本語", true),
+            ("^\\p{L}+$", "a1", false),
+            ("^\\pL\\pN$", "é٣", true),
+            ("^\\p{Lu}+$", "ÀB", true),
+            ("^\\p{Lu}+$", "Àb", false),
+            ("^\\p{Uppercase_Letter}$", "Ω", true),
+            ("^\\p{lowercase letter}$", "ω", true),
+            ("^\\p{Greek}+$", "αβγ", true),
+            ("^\\p{Greek}+$", "abc", false),
+            ("^\\p{Script=Han}+$", "漢字", true),
+            ("^\\p{sc=Hani}+$", "漢字", true),
+            ("^\\p{gc=Nd}+$", "0١", true),
+            ("^\\p{Alphabetic}+$", "Ⅻ", true),
+            ("^\\P{L}+$", "1 2", true),
+            ("^\\P{L}+$", "1a", false),
+            ("^\\PL$", "!", true),
+            ("^[\\p{N}\\s]+$", "1 ٢\t3", true),
+            ("^[^\\p{Greek}\\d]+$", "abc", true),
+            ("^[^\\p{Greek}\\d]+$", "aβc", false),
+            ("^[\\P{L}]+$", "1a", false),
+            ("^(?i)\\p{Lu}+$", "abc", true),
+            ("^\\p{Any}+$", "a\n日", true),
+            ("^\\p{ASCII}+$", "a日", false),
+        ];
+        test(&test_cases);
+    }
+
     #[test]
     fn quantifier() {
         let test_cases = vec![
@@ -97,6 +462,40 @@ mod tests {
         test(&test_cases);
     }
 
+    #[test]
+    fn dot_all() {
+        let test_cases = [
+            ("a.b", "a\nb", false),
+            ("a.b", "a\rb", true),
+            ("(?s)a.b", "a\nb", true),
+            ("(?s:a.)b", "a\nb", true),
+            ("(?s:a)Xb", "a\nb", false),
+            ("(?R)a.b", "a\rb", false),
+            ("(?R)a.b", "a\nb", false),
+            ("(?sR)a.b", "a\rb", true),
+            ("[^a]", "\n", true),
+        ];
+        test(&test_cases);
+
+        test_find(&[(".*", "first\nsecond", Some((0, 5))), ("(?s).*", "first\nsecond", Some((0, 12)))]);
+
+        let re = RegexBuilder::new("a.b").dot_matches_new_line(true).build().unwrap();
+        assert!(re.is_match("a\nb"));
+        let re = RegexBuilder::new("^.+$").line_terminator(b'\0').multi_line(true).build().unwrap();
+        let records: Vec<_> = re.find_iter("one\0two\nlines\0").map(|m| m.as_str()).collect();
+        assert_eq!(records, ["one", "two\nlines"]);
+        let re = RegexBuilder::new("^.+$")
+            .line_terminator(b'\0')
+            .crlf(true)
+            .multi_line(true)
+            .build()
+            .unwrap();
+        let records: Vec<_> = re.find_iter("a\0b\r\nc").map(|m| m.as_str()).collect();
+        assert_eq!(records, ["a\0b", "c"]);
+        let err = RegexBuilder::new("a").line_terminator(0xFF).build().unwrap_err();
+        assert_eq!((err.kind(), err.offset()), (ErrorKind::InvalidLineTerminator, 0));
+    }
+
     #[test]
     fn character_classes_combined() {
         let test_cases = vec![
@@ -258,4 +657,471 @@ mod tests {
 
         test(&test_cases)
     }
+
+    #[test]
+    fn find() {
+        let test_cases = vec![
+            ("dog", "hot dog stand", Some((4, 7))),
+            ("ca+t", "a caaat!", Some((2, 7))),
+            ("\\d+", "abc 12345 def", Some((4, 9))),
+            ("a (cat|dog)", "see a dog", Some((4, 9))),
+            ("ab", "aab", Some((1, 3))),
+            ("x*", "abc", Some((0, 0))),
+            ("$", "abc", Some((3, 3))),
+            ("cat", "dog", None),
+        ];
+        test_find(&test_cases);
+
+        let m = Regex::new("(\\w+)@(\\w+)").unwrap().find("mail me@example now").unwrap();
+        assert_eq!(m.as_str(), "me@example");
+        assert_eq!(m.range(), 5..15);
+        assert_eq!(Regex::new("\\d\\d").unwrap().shortest_match("ab 123"), Some(5));
+        assert_eq!(Regex::new("a+").unwrap().shortest_match("baaa"), Some(2));
+        assert!(Regex::new("\\d").unwrap().is_match("ab 1"));
+    }
+
+    #[test]
+    fn find_iter() {
+        let test_cases = [
+            ("\\d+", "1 22 333", vec![(0, 1), (2, 4), (5, 8)]),
+            ("cat|dog", "cat, dog, cow", vec![(0, 3), (5, 8)]),
+            ("a*", "baaa", vec![(0, 0), (1, 4), (4, 4)]),
+            ("x*", "", vec![(0, 0)]),
+            ("a", "bbb", vec![]),
+        ];
+        for (i, test) in test_cases.iter().enumerate() {
+            let result: Vec<_> = Regex::new(test.0).unwrap().find_iter(test.1).map(|m| (m.start(), m.end())).collect();
+            assert_eq!(result, test.2, "Test case {} failed: ({}, {})", i, test.0, test.1);
+        }
+    }
+
+    #[test]
+    fn captures() {
+        let re = Regex::new("(\\w+)=(\\d+)( ms)?").unwrap();
+        assert_eq!(re.captures_len(), 4);
+
+        let caps = re.captures("took: latency=250 ok").unwrap();
+        assert_eq!(caps.len(), 4);
+        assert_eq!(&caps[0], "latency=250");
+        assert_eq!(&caps[1], "latency");
+        assert_eq!(caps.get(2).map(|m| m.range()), Some(14..17));
+        assert_eq!(caps.get(3), None);
+        let groups: Vec<_> = caps.iter().map(|m| m.map(|m| m.as_str())).collect();
+        assert_eq!(groups, vec![Some("latency=250"), Some("latency"), Some("250"), None]);
+        assert_eq!(format!("{:?}", caps.get(2).unwrap()), "Match { start: 14, end: 17, string: \"250\" }");
+        let caps = Regex::new("(a)(x)?").unwrap().captures("a very long text").unwrap();
+        assert_eq!(
+            format!("{:?}", caps),
+            "Captures([Some(Match { start: 0, end: 1, string: \"a\" }), Some(Match { start: 0, end: 1, string: \"a\" }), None])"
+        );
+
+        let re = Regex::new("((c.t|d.g) and (f..h|b..d)), \\2").unwrap();
+        let caps = re.captures("cat and fish, cat").unwrap();
+        assert_eq!((&caps[1], &caps[2], &caps[3]), ("cat and fish", "cat", "fish"));
+
+        let re = Regex::new("(\\w)(\\d)").unwrap();
+        let pairs: Vec<_> = re.captures_iter("a1 b2 c3").map(|caps| format!("{}{}", &caps[2], &caps[1])).collect();
+        assert_eq!(pairs, vec!["1a", "2b", "3c"]);
+        assert!(re.captures("ab").is_none());
+    }
+
+    #[test]
+    fn named_captures() {
+        let re = Regex::new("(?<year>\\d\\d\\d\\d)-(?'month'\\d\\d)-(?P<day>\\d\\d) (\\w+)").unwrap();
+        let names: Vec<_> = re.capture_names().collect();
+        assert_eq!(names, vec![None, Some("year"), Some("month"), Some("day"), None]);
+
+        let caps = re.captures("on 2024-03-15 at noon").unwrap();
+        assert_eq!((&caps["year"], &caps["month"], &caps["day"], &caps[4]), ("2024", "03", "15", "at"));
+        assert_eq!(caps.name("month").map(|m| m.start()), Some(8));
+        assert_eq!(caps.name("hour"), None);
+    }
+
+    #[test]
+    fn backreference_named() {
+        let test_cases = vec![
+            ("<(?<tag>\\w+)>\\w+</\\k<tag>>", "<b>bold</b>", true),
+            ("<(?<tag>\\w+)>\\w+</\\k<tag>>", "<b>bold</i>", false),
+            ("(?'word'\\w+) \\k'word'", "hello hello", true),
+            ("(?<word>\\w+) \\k{word}", "hello world", false),
+            ("(?P<x>\\d)(\\d)(?P=x)", "121", true),
+            ("(?P<x>\\d)(\\d)(?P=x)", "123", false),
+            ("^(a)\\1+$", "aaa", true),
+            ("^(a)\\1+$", "aa+", false),
+            ("^(?<x>a)\\k<x>{2}$", "aaa", true),
+            ("^(?<x>a)\\k<x>{2}$", "aa{2}", false),
+            ("^(?P<x>a)(?P=x)*b$", "aaab", true),
+            ("^(?P<x>a)(?P=x)*b$", "ab", true),
+            ("^(a)\\1{1,2}$", "aaaa", false),
+        ];
+        test(&test_cases);
+
+        // the backtracking matcher keeps its own stack, so long matches can't overflow the call stack
+        let text = format!("{}b", "a".repeat(100_000));
+        assert_eq!(Regex::new("(a)\\1*b").unwrap().find(&text).map(|m| m.len()), Some(100_001));
+    }
+
+    #[test]
+    fn range_quantifier() {
+        let test_cases = vec![
+            ("^\\d{3}-\\d{4}$", "555-1234", true),
+            ("^\\d{3}-\\d{4}$", "55-1234", false),
+            ("^\\d{3}-\\d{4}$", "5555-1234", false),
+            ("^a{2,}$", "a", false),
+            ("^a{2,}$", "aaaaa", true),
+            ("^a{2,3}$", "aaa", true),
+            ("^a{2,3}$", "aaaa", false),
+            ("^(ab){0}c$", "c", true),
+            ("^(ab){0,1}c$", "abc", true),
+            ("^x{,3}$", "x{,3}", true),
+            ("^a{b$", "a{b", true),
+            ("^{}$", "{}", true),
+        ];
+        test(&test_cases);
+
+        test_find(&[("a{2,4}", "caaaaaa", Some((1, 5))), ("(b|c){2}", "abcd", Some((1, 3)))]);
+
+        let re = Regex::new("(?<a>x)(y){0}(z)").unwrap();
+        assert_eq!(re.captures_len(), 4);
+        assert_eq!(re.captures("xz").unwrap().get(3).map(|m| m.as_str()), Some("z"));
+
+        assert!(RegexBuilder::new("a{1001}").build().is_err());
+        assert!(RegexBuilder::new("a{1001}").repetition_limit(2000).build().is_ok());
+        assert!(RegexBuilder::new("a{5}").repetition_limit(4).build().is_err());
+        assert!(Regex::new("(?:(?:a{10}){10}){10}").is_ok());
+        assert!(RegexBuilder::new("(?:(?:a{10}){10}){10}").size_limit(1000).build().is_err());
+        assert!(Regex::new("(?:a{1000}){100}").is_err());
+        assert!(RegexBuilder::new("(?:a{1000}){100}").size_limit(300_000).build().is_ok());
+    }
+
+    #[test]
+    fn lazy_quantifier() {
+        let test_cases = vec![
+            ("\".*\"", "say \"hi\" and \"bye\"", Some((4, 18))),
+            ("\".*?\"", "say \"hi\" and \"bye\"", Some((4, 8))),
+            ("a+?", "aaa", Some((0, 1))),
+            ("a*?", "aaa", Some((0, 0))),
+            ("a??b", "ab", Some((0, 2))),
+            ("a{2,4}?", "aaaaa", Some((0, 2))),
+            ("a{2,}?", "aaaaa", Some((0, 2))),
+            ("<(.+?)>", "<a><b>", Some((0, 3))),
+        ];
+        test_find(&test_cases);
+
+        let re = Regex::new("(\\w+?)(\\d*)$").unwrap();
+        let caps = re.captures("abc123").unwrap();
+        assert_eq!((&caps[1], &caps[2]), ("abc", "123"));
+    }
+
+    #[test]
+    fn non_capturing_group() {
+        let test_cases = vec![
+            ("^(?:cat|dog)s?$", "dogs", true),
+            ("^(?:cat|dog)s?$", "cow", false),
+            ("(?:a|b)(c)\\1", "bcc", true),
+            ("(?:a|b)(c)\\1", "bcb", false),
+            ("^(?:ab){2}$", "abab", true),
+        ];
+        test(&test_cases);
+
+        let re = Regex::new("(?:(\\w+)=)?(\\d+)").unwrap();
+        assert_eq!(re.captures_len(), 3);
+        let caps = re.captures("x=42").unwrap();
+        assert_eq!((&caps[1], &caps[2]), ("x", "42"));
+        let caps = re.captures("42").unwrap();
+        assert_eq!((caps.get(1), &caps[2]), (None, "42"));
+    }
+
+    #[test]
+    fn case_insensitive() {
+        let test_cases = vec![
+            ("cat$", "CAT", false),
+            ("[abc]", "B", false),
+            ("(?i)cat$", "CAT", true),
+            ("(?i)[abc]", "B", true),
+            ("(?i)[^abc]", "B", false),
+            ("(?i)c(?-i)at", "CAT", false),
+            ("(?i)c(?-i)at", "Cat", true),
+            ("a(?i:b)c", "aBc", true),
+            ("a(?i:b)c", "aBC", false),
+            ("a((?i)b)c", "aBC", false),
+            ("(?i)a|b", "B", true),
+        ];
+        test(&test_cases);
+
+        let re = RegexBuilder::new("hello (world)").case_insensitive(true).build().unwrap();
+        assert_eq!(&re.captures("HeLLo WORLD").unwrap()[1], "WORLD");
+        let re = RegexBuilder::new("hello (?-i)world").case_insensitive(true).build().unwrap();
+        assert!(re.is_match("HELLO world"));
+        assert!(!re.is_match("HELLO WORLD"));
+    }
+
+    #[test]
+    fn case_folding() {
+        let test_cases = vec![
+            ("(?i)straße", "STRAẞE", true),
+            ("(?i)straße", "STRASSE", false),
+            ("(?i)σ", "Σ", true),
+            ("(?i)Σ", "ς", true),
+            ("(?i)[ς]", "σ", true),
+            ("(?i)кот", "КОТ", true),
+            ("(?i)k", "\u{212A}", true),
+            ("(?i)(ΟΔΟΣ) \\1", "οδος ΟΔΟΣ", true),
+            ("(\\w+) \\1", "abc ABC", false),
+            ("(?i)(\\w+) \\1", "abc ABC", true),
+        ];
+        test(&test_cases);
+
+        let re = RegexBuilder::new("straße")
+            .case_insensitive(true)
+            .full_case_folding(true)
+            .build()
+            .unwrap();
+        assert_eq!(re.find("DIE STRASSE").map(|m| m.range()), Some(4..11));
+        assert!(re.is_match("straẞe"));
+        assert!(!re.is_match("STRASE"));
+
+        // an empty lookahead requires backtracking, so every case also runs on the backtracking matcher
+        for prefix in ["", "(?=)"] {
+            let full = |pattern: &str| {
+                RegexBuilder::new(&format!("{}{}", prefix, pattern))
+                    .case_insensitive(true)
+                    .full_case_folding(true)
+                    .build()
+                    .unwrap()
+            };
+            let test_cases = [
+                ("^strasse$", "STRAßE", true),
+                ("^STRASSE$", "straße", true),
+                ("^strasse$", "straẞe", true),
+                ("^ss$", "ß", true),
+                ("^s(s)$", "ß", false),
+                ("^[ß]$", "SS", true),
+                ("^[ß]$", "ß", true),
+                ("^[a-ß]$", "sS", true),
+                ("^[ẞ]$", "ss", true),
+                ("^[^ß]$", "SS", false),
+                ("^(ß) \\1$", "ß SS", true),
+                ("^(SS) \\1$", "ss ß", true),
+                ("^(ß) \\1$", "ß S", false),
+                ("^(?-i:ss)$", "ß", false),
+                ("^ﬀ$", "FF", true),
+                ("^ff$", "ﬀ", true),
+            ];
+            for (i, test) in test_cases.iter().enumerate() {
+                assert_eq!(
+                    full(test.0).is_match(test.1),
+                    test.2,
+                    "Test case {} failed: ({}{}, {})",
+                    i,
+                    prefix,
+                    test.0,
+                    test.1
+                );
+            }
+
+            let test_cases = [
+                ("ss|s", "ß", Some((0, 2))),
+                ("s|ss", "ß", Some((0, 2))),
+                ("(?:ss)+", "sßSSs", Some((1, 5))),
+                ("a[ß]*b", "aßssSSb", Some((0, 8))),
+                ("(?:sss|ß)s", "ßs", Some((0, 3))),
+                ("(?:s|ß)(?:ss)?s", "sßs", Some((0, 4))),
+            ];
+            for (i, test) in test_cases.iter().enumerate() {
+                let result = full(test.0).find(test.1).map(|m| (m.start(), m.end()));
+                assert_eq!(result, test.2, "Test case {} failed: ({}{}, {})", i, prefix, test.0, test.1);
+            }
+            let caps = full("(s)?(ss)(s)").captures("ßßs").unwrap();
+            assert_eq!((caps.get(1).map(|m| m.range()), caps.get(2).map(|m| m.range())), (None, Some(2..4)));
+        }
+    }
+
+    #[test]
+    fn utf8() {
+        let test_cases = vec![
+            ("c.t", "c😀t", true),
+            ("^.{3}$", "日本語", true),
+            ("^.{3}$", "日本", false),
+            ("[^a]本", "日本", true),
+            ("(..)-\\1", "日本-日本", true),
+            ("(?-u)\\d+", "٣", false),
+        ];
+        test(&test_cases);
+
+        test_find(&[
+            ("語", "日本語", Some((6, 9))),
+            ("😀+", "a😀😀b", Some((1, 9))),
+            ("(?-u)\\w", "é1", Some((2, 3))),
+        ]);
+
+        let result: Vec<_> = Regex::new("é*").unwrap().find_iter("aéé").map(|m| m.range()).collect();
+        assert_eq!(result, vec![0..0, 1..5, 5..5]);
+        let result: Vec<_> = Regex::new("x*").unwrap().find_iter("日本").map(|m| m.start()).collect();
+        assert_eq!(result, vec![0, 3, 6]);
+
+        let caps = Regex::new("(\\w+) (.+)").unwrap().captures("emoji 🎉🎉!").unwrap();
+        assert_eq!(&caps[2], "🎉🎉!");
+    }
+
+    #[test]
+    fn pike_vm() {
+        let text = "a".repeat(40);
+        assert!(!Regex::new("(a|a)*b").unwrap().is_match(&text));
+        assert!(!Regex::new("(a*)*b").unwrap().is_match(&text));
+        let re = RegexBuilder::new("(?:a|a)*classb")
+            .case_insensitive(true)
+            .full_case_folding(true)
+            .build()
+            .unwrap();
+        assert!(!re.is_match(&text));
+
+        let text = "a".repeat(100_000);
+        assert_eq!(Regex::new("a*").unwrap().find(&text).map(|m| m.len()), Some(100_000));
+        assert_eq!(
+            Regex::new("(a|b)+$").unwrap().captures(&text).unwrap().get(1).map(|m| m.start()),
+            Some(99_999)
+        );
+
+        // the leftmost match is preferred, then the first alternative that matches
+        test_find(&[
+            ("a|ab", "ab", Some((0, 1))),
+            ("ab|a", "ab", Some((0, 2))),
+            ("a+?b", "aaab", Some((0, 4))),
+            ("(a|ab)(c|bcd)", "abcd", Some((0, 4))),
+        ]);
+        let caps = Regex::new("(a|ab)(c|bcd)(d*)").unwrap().captures("abcd").unwrap();
+        assert_eq!((&caps[1], &caps[2], &caps[3]), ("a", "bcd", ""));
+    }
+
+    #[test]
+    fn empty_expression() {
+        // an empty lookahead requires backtracking, so every case also runs on the backtracking matcher
+        for prefix in ["", "(?=)"] {
+            let regex = |pattern: &str| Regex::new(&format!("{}{}", prefix, pattern)).unwrap();
+            let test_cases = [
+                ("", "abc", Some((0, 0))),
+                ("a|", "b", Some((0, 0))),
+                ("(|a)+", "aa", Some((0, 2))),
+                ("()*", "x", Some((0, 0))),
+                ("(a*)*", "aab", Some((0, 2))),
+                ("(a?)+b", "aab", Some((0, 3))),
+                ("(a*)+?b", "aab", Some((0, 3))),
+                ("(?:a*|b)*c", "abbac", Some((0, 5))),
+                ("(?:a?b??)*", "ab", Some((0, 2))),
+                ("((bb)?(?:.)*?)+", "abba", Some((0, 4))),
+                ("(?:(?:a*b??(?:bb??a|)*?)*(?:(bb)?(?:.)b)*?a?)?", "ab", Some((0, 2))),
+            ];
+            for (i, test) in test_cases.iter().enumerate() {
+                let result = regex(test.0).find(test.1).map(|m| (m.start(), m.end()));
+                assert_eq!(result, test.2, "Test case {} failed: ({}{}, {})", i, prefix, test.0, test.1);
+            }
+
+            let caps = regex("(a*)+").captures("aa").unwrap();
+            assert_eq!((caps.get(0).map(|m| m.range()), caps.get(1).map(|m| m.range())), (Some(0..2), Some(0..2)));
+            // an iteration that matches the empty string fails, unless it is a required one as the
+            // first of a + or the first n of x{n,m}
+            for pattern in ["(a|)*", "(a|){0,}", "(a|){0,5}", "(a|)+", "(a|){1,5}", "(a|){2,}"] {
+                let caps = regex(pattern).captures("aa").unwrap();
+                assert_eq!(caps.get(1).map(|m| m.range()), Some(1..2), "{}{}", prefix, pattern);
+            }
+            for (pattern, group) in [("(a|)?", None), ("(a|){0,5}", None), ("(a|){1,5}", Some(0..0)), ("(a|){2,3}", Some(0..0))] {
+                let caps = regex(pattern).captures("").unwrap();
+                assert_eq!(caps.get(1).map(|m| m.range()), group, "{}{}", prefix, pattern);
+            }
+            let caps = regex("(a|){2,3}").captures("a").unwrap();
+            assert_eq!(caps.get(1).map(|m| m.range()), Some(1..1));
+            let caps = regex("(a*)*").captures("b").unwrap();
+            assert_eq!(caps.get(1), None);
+            let caps = regex("(a*)+").captures("b").unwrap();
+            assert_eq!(caps.get(1).map(|m| m.range()), Some(0..0));
+            let caps = regex("(a?b??(a*(?:a?)*?(?:a|b??)*?)+?)*").captures("aabbb").unwrap();
+            assert_eq!(caps.get(2).map(|m| m.range()), Some(4..5));
+        }
+
+        // the same patterns on the backtracking matcher
+        test(&[
+            ("(a*)*b\\1", "aabaa", true),
+            ("(a?)+b\\1", "aabc", true),
+            ("(?:()|a)+x\\1", "x", true),
+            ("(?:()|a)*x\\1", "aax", false),
+            ("(a*)*x\\1", "aab", false),
+        ]);
+        let caps = Regex::new("(a*)+\\1").unwrap().captures("aa").unwrap();
+        assert_eq!(caps.get(1).map(|m| m.range()), Some(0..1));
+        let caps = Regex::new("(a|)*\\1").unwrap().captures("aa").unwrap();
+        assert_eq!(caps.get(1).map(|m| m.range()), Some(0..1));
+    }
+
+    #[test]
+    fn lookahead() {
+        let test_cases = [
+            ("^(?=.*\\d)(?=.*[A-Z]).{8,}$", "secret1Password", true),
+            ("^(?=.*\\d)(?=.*[A-Z]).{8,}$", "secretPassword", false),
+            ("^(?=.*\\d)(?=.*[A-Z]).{8,}$", "secret1password", false),
+            ("^(?=.*\\d)(?=.*[A-Z]).{8,}$", "Secret1", false),
+            ("foo(?=bar)", "foobar", true),
+            ("foo(?=bar)", "foobaz", false),
+            ("foo(?!bar)", "foobar", false),
+            ("foo(?!bar)", "foobaz", true),
+            ("(?=a)b", "ab", false),
+            ("^(?!$)", "", false),
+            ("(?=(a+))a*b\\1", "baaabac", true),
+            ("^(?=(a+))a\\1$", "aa", false),
+            ("(?!(a)b)\\w\\1", "abaa", false),
+            ("(?i)x(?=A)", "xa", true),
+            ("(?=a|(b))\\w+", "ab", true),
+            ("(?!(?=a)b)", "a", true),
+            ("foo(?=bar)?", "foobaz", true),
+            ("^foo(?=bar)?$", "foo", true),
+            ("foo(?=bar)+", "foobaz", false),
+            ("foo(?=bar){2}", "foobar", true),
+            ("foo(?!bar)*", "foobar", true),
+            ("foo(?=bar)?\\?", "foo?", true),
+        ];
+        test(&test_cases);
+
+        test_find(&[
+            ("\\w+(?=,)", "one, two", Some((0, 3))),
+            ("\\b(?!un)\\w+", "undo redo", Some((5, 9))),
+            ("(?=(\\d+))\\w+\\1", "ab 123x12312", Some((3, 10))),
+        ]);
+
+        // captures in a positive lookahead are kept, and those in a negative one never set
+        let caps = Regex::new("(?=(\\w+))(\\w)").unwrap().captures("abc").unwrap();
+        assert_eq!((&caps[1], &caps[2]), ("abc", "a"));
+        let caps = Regex::new("(?!(x))(a)").unwrap().captures("a").unwrap();
+        assert_eq!((caps.get(1), &caps[2]), (None, "a"));
+        let caps = Regex::new("(?:(?=(a))ab|a)c").unwrap().captures("ac").unwrap();
+        assert_eq!(caps.get(1), None);
+        // an optional iteration may not match the empty string, so (?=(y))? never keeps its capture
+        let caps = Regex::new("x(?=(y))?").unwrap().captures("xy").unwrap();
+        assert_eq!(caps.get(1), None);
+        let caps = Regex::new("x(?=(y))+").unwrap().captures("xy").unwrap();
+        assert_eq!(caps.get(1).map(|m| m.range()), Some(1..2));
+    }
+
+    #[test]
+    fn shared_between_threads() {
+        fn assert_send_sync_clone<T: Send + Sync + Clone>() {}
+        assert_send_sync_clone::<Regex>();
+
+        let re = Regex::new("(\\w+)@(\\w+)").unwrap();
+        std::thread::scope(|scope| {
+            for i in 0..4 {
+                let re = &re;
+                scope.spawn(move || {
+                    for _ in 0..100 {
+                        let text = format!("user{}@example", i);
+                        let caps = re.captures(&text).unwrap();
+                        assert_eq!(&caps[1], format!("user{}", i));
+                    }
+                });
+            }
+        });
+
+        let clone = re.clone();
+        assert_eq!(clone.as_str(), re.as_str());
+        assert!(clone.is_match("me@example"));
+    }
 }
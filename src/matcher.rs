@@ -1,79 +1,195 @@
-use std::collections::HashMap;
+use std::mem;
 
-use crate::compiler::{CompiledMachine, ConditionResult, Cursor, StateRef};
+use crate::pikevm::{self, PikeVM};
+use crate::pool::PoolGuard;
+use crate::program::{self, Program, State, StateId};
 
-pub(crate) struct Matcher<'a> {
-    machine: CompiledMachine,
-    cursor: Cursor<'a>,
-    start_captured_groups: HashMap<usize, Vec<usize>>, // map a start state id to its captured group indices
-    end_captured_groups: HashMap<usize, Vec<usize>>,   // map an end state id to its captured group indices
+pub(crate) struct Matcher<'r, 't> {
+    program: &'r Program,
+    cache: PoolGuard<'r, Cache>,
+    text: &'t str,
+    at: usize,           // byte offset where the next search starts
+    previous_end: usize, // where the previous match ended, which is where \G matches
+    done: bool,
 }
 
-impl Matcher<'_> {
-    pub(crate) fn new(machine: CompiledMachine, text: &str) -> Matcher {
-        let cursor = Cursor::new(text);
-        let mut start_captured_groups = HashMap::new();
-        let mut end_captured_groups = HashMap::new();
+// the scratch space of the matchers, kept in a pool by each regex
+#[derive(Default)]
+pub(crate) struct Cache {
+    pikevm: pikevm::Cache,
+    stack: Vec<Job>,
+}
 
-        for (index, group) in machine.captured_groups.iter().enumerate() {
-            start_captured_groups.entry(group.start.borrow().id).or_insert_with(Vec::new).push(index);
-            end_captured_groups.entry(group.end.borrow().id).or_insert_with(Vec::new).push(index);
-        }
+// the work left to the backtracking matcher, kept on a stack so that long matches can't
+// overflow the call stack
+enum Job {
+    Explore(StateId, usize),
+    RestoreSlot(usize, Option<usize>), // undo a save when backtracking past it
+}
 
+impl<'r, 't> Matcher<'r, 't> {
+    pub(crate) fn new(program: &'r Program, cache: PoolGuard<'r, Cache>, text: &'t str) -> Matcher<'r, 't> {
         Matcher {
-            machine,
-            cursor,
-            start_captured_groups,
-            end_captured_groups,
+            program,
+            cache,
+            text,
+            at: 0,
+            previous_end: 0,
+            done: false,
         }
     }
 
     pub(crate) fn matches(&mut self) -> bool {
-        // overlapping matches are not supported
-        let mut cursor = self.cursor.clone();
-        let mut start_captured_group_indices = HashMap::new();
-        while !self.try_match(&mut cursor, self.machine.fsm.start.clone(), &mut start_captured_group_indices) {
-            cursor.advance(1);
-            if cursor.is_end() {
-                return false;
-            }
-            start_captured_group_indices.clear();
-        }
+        self.search(true).is_some()
+    }
 
-        self.cursor = cursor;
-        true
+    // returns the byte range of the leftmost match starting at or after the end of the previous one
+    pub(crate) fn find(&mut self) -> Option<(usize, usize)> {
+        self.search(false).map(|slots| (slots[0].unwrap(), slots[1].unwrap()))
     }
 
-    fn try_match(&self, cursor: &mut Cursor, state: StateRef, start_captured_group_indices: &mut HashMap<usize, usize>) -> bool {
-        // println!("{:?} '{}'", state.borrow().id, cursor.char().unwrap_or_default());
+    // returns the end of the first match found, which may end before the one find would return
+    pub(crate) fn shortest(&mut self) -> Option<usize> {
+        self.search(true).map(|slots| slots[1].unwrap())
+    }
 
-        if state.borrow().id == self.machine.fsm.end.borrow().id {
-            return true;
+    // like find, but also returns the byte range of every captured group, with group 0 being the whole match
+    pub(crate) fn captures(&mut self) -> Option<Vec<Option<(usize, usize)>>> {
+        self.search(false).map(|slots| slots.chunks(2).map(|slot| slot[0].zip(slot[1])).collect())
+    }
+
+    fn search(&mut self, earliest: bool) -> Option<Vec<Option<usize>>> {
+        if self.done {
+            return None;
         }
 
-        if let Some(indices) = self.start_captured_groups.get(&state.borrow().id) {
-            for &i in indices {
-                let group = &self.machine.captured_groups[i];
-                start_captured_group_indices.insert(group.index, cursor.index);
+        let slots = if self.program.requires_backtracking {
+            self.backtrack()
+        } else {
+            PikeVM::new(self.program, self.text, self.previous_end).search(&mut self.cache.pikevm, self.at, earliest)
+        };
+        let mut slots = match slots {
+            Some(slots) => slots,
+            None => {
+                self.done = true;
+                return None;
             }
-        }
+        };
 
-        if let Some(indices) = self.end_captured_groups.get(&state.borrow().id) {
-            for &i in indices {
-                let group = &self.machine.captured_groups[i];
-                if let Some(&start_index) = start_captured_group_indices.get(&group.index) {
-                    cursor.add_captured_group(group.index, start_index, cursor.index)
-                }
+        // overlapping matches are not supported
+        let (start, end) = (slots[0].unwrap(), slots[1].unwrap());
+        self.at = end;
+        self.previous_end = end;
+        if start == end {
+            // an empty match would be found again at the same position, so resume one char later
+            match program::char_at(self.text, self.at) {
+                Some(ch) => self.at += ch.len_utf8(),
+                None => self.done = true,
             }
         }
+        slots.truncate(self.program.capture_slots_len());
+        Some(slots)
+    }
+
+    fn backtrack(&mut self) -> Option<Vec<Option<usize>>> {
+        let mut slots = vec![None; self.program.slots_len()];
+        let mut stack = mem::take(&mut self.cache.stack);
+        let mut start = self.at;
+        let matched = loop {
+            stack.clear();
+            if self.run(&mut stack, self.program.start, start, &mut slots) {
+                break true;
+            }
+
+            match program::char_at(self.text, start) {
+                Some(ch) => start += ch.len_utf8(),
+                None => break false,
+            }
+        };
+        self.cache.stack = stack;
+        matched.then_some(slots)
+    }
+
+    // tries the transitions depth first, in order, and stops at the first path reaching the match state,
+    // or the end of the lookahead being run; every save is undone on failure, so slots are left as they
+    // were. On success, the jobs that undo the saves on the path are left on the stack, so that a
+    // lookahead's captures are kept until the matcher backtracks past it.
+    fn run(&self, stack: &mut Vec<Job>, state: StateId, at: usize, slots: &mut [Option<usize>]) -> bool {
+        let base = stack.len();
+        stack.push(Job::Explore(state, at));
+        while stack.len() > base {
+            let (mut state, mut at) = match stack.pop().unwrap() {
+                Job::Explore(state, at) => (state, at),
+                Job::RestoreSlot(slot, value) => {
+                    slots[slot] = value;
+                    continue;
+                }
+            };
 
-        for transition in &state.borrow().transitions {
-            if let ConditionResult::Accepted(n) = (transition.condition.evaluate)(cursor) {
-                cursor.advance(n);
-                let mut cloned_cursor = cursor.clone();
-                if self.try_match(&mut cloned_cursor, transition.target.clone(), start_captured_group_indices) {
-                    *cursor = cloned_cursor;
-                    return true;
+            // follow the first transition right away and leave the others for later
+            loop {
+                match &self.program.states[state] {
+                    State::Match | State::LookaheadEnd => {
+                        // a lookahead is atomic, the other paths through it are never tried
+                        let mut kept = base;
+                        for i in base..stack.len() {
+                            if let Job::RestoreSlot(..) = stack[i] {
+                                stack.swap(kept, i);
+                                kept += 1;
+                            }
+                        }
+                        stack.truncate(kept);
+                        return true;
+                    }
+                    State::Split(targets) => match targets.split_first() {
+                        Some((first, rest)) => {
+                            stack.extend(rest.iter().rev().map(|&target| Job::Explore(target, at)));
+                            state = *first;
+                        }
+                        None => break,
+                    },
+                    State::Save { slot, next } => {
+                        stack.push(Job::RestoreSlot(*slot, slots[*slot]));
+                        slots[*slot] = Some(at);
+                        state = *next;
+                    }
+                    State::Assertion { assertion, next } => {
+                        if !assertion.matches(self.text, at, self.previous_end) {
+                            break;
+                        }
+                        state = *next;
+                    }
+                    State::Progress { slot, next } => {
+                        if slots[*slot].is_some_and(|start| at <= start) {
+                            break;
+                        }
+                        state = *next;
+                    }
+                    State::Lookahead { negative, start, next } => {
+                        let len = stack.len();
+                        let matched = self.run(stack, *start, at, slots);
+                        if matched && *negative {
+                            // a negative lookahead keeps no captures
+                            while stack.len() > len {
+                                if let Some(Job::RestoreSlot(slot, value)) = stack.pop() {
+                                    slots[slot] = value;
+                                }
+                            }
+                        }
+                        if matched == *negative {
+                            break;
+                        }
+                        state = *next;
+                    }
+                    consuming @ (State::Char { next, .. } | State::Folded { next, .. } | State::Class { next, .. } | State::Backref { next, .. }) => {
+                        match program::consume(consuming, self.text, at, slots) {
+                            Some(n) => {
+                                at += n;
+                                state = *next;
+                            }
+                            None => break,
+                        }
+                    }
                 }
             }
         }
@@ -0,0 +1,73 @@
+use std::fmt;
+use std::ops::Range;
+
+use crate::matcher::Matcher;
+
+/// A single match of a regex in a text, with byte offsets into the text.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Match<'t> {
+    text: &'t str,
+    start: usize,
+    end: usize,
+}
+
+impl<'t> Match<'t> {
+    pub(crate) fn new(text: &'t str, start: usize, end: usize) -> Match<'t> {
+        Match { text, start, end }
+    }
+
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    pub fn as_str(&self) -> &'t str {
+        &self.text[self.range()]
+    }
+}
+
+// the text may be a whole file, so only the matched part is shown
+impl fmt::Debug for Match<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Match")
+            .field("start", &self.start)
+            .field("end", &self.end)
+            .field("string", &self.as_str())
+            .finish()
+    }
+}
+
+/// An iterator over all non-overlapping matches in a text, created by `Regex::find_iter`.
+pub struct Matches<'r, 't> {
+    text: &'t str,
+    matcher: Matcher<'r, 't>,
+}
+
+impl<'r, 't> Matches<'r, 't> {
+    pub(crate) fn new(text: &'t str, matcher: Matcher<'r, 't>) -> Matches<'r, 't> {
+        Matches { text, matcher }
+    }
+}
+
+impl<'t> Iterator for Matches<'_, 't> {
+    type Item = Match<'t>;
+
+    fn next(&mut self) -> Option<Match<'t>> {
+        self.matcher.find().map(|(start, end)| Match::new(self.text, start, end))
+    }
+}
@@ -1,27 +1,53 @@
-use anyhow::{anyhow, Result};
-use std::{fmt, iter::Peekable, str::Chars};
+use crate::builder::Config;
+use crate::error::{Error, ErrorKind};
+use crate::unicode::{self, Property};
+use std::{collections::HashMap, fmt, iter::Peekable, str::Chars};
+
+type Result<T> = std::result::Result<T, Error>;
 
 #[derive(Clone, Debug, PartialEq)]
 pub(crate) enum CharacterClass {
     Char(char),
     // String(String),
-    Digit,                                                    // \d
-    Word,                                                     // \w
+    Shorthand(Shorthand),                                     // \d \w \s
+    Property { negative: bool, property: Property },          // \p{L} \P{Greek}
     Wildcard,                                                 // .
     Group { negative: bool, items: Vec<CharacterGroupItem> }, // [abc] [^abc]
 }
 
 #[derive(Clone, Debug, PartialEq)]
 pub(crate) enum CharacterGroupItem {
-    Digit, // \d
-    Word,  // \w
+    Shorthand(Shorthand),                            // \d \w \s
+    Property { negative: bool, property: Property }, // \p{L} \P{Greek}
     Char(char),
+    Range(char, char), // a-z
+}
+
+// the escapes for common classes of chars, on their own or in a character group
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Shorthand {
+    Digit,           // \d
+    NotDigit,        // \D
+    Word,            // \w
+    NotWord,         // \W
+    Space,           // \s
+    NotSpace,        // \S
+    HorizontalSpace, // \h
+    VerticalSpace,   // \v
 }
 
 #[derive(Clone, Debug, PartialEq)]
 pub(crate) enum Anchor {
-    StartOfString, // ^
-    EndOfString,   // $
+    StartOfLine,             // ^
+    EndOfLine,               // $
+    WordBoundary,            // \b
+    NotWordBoundary,         // \B
+    StartOfWord,             // \b{start}
+    EndOfWord,               // \b{end}
+    StartOfText,             // \A
+    EndOfText,               // \z
+    EndOfTextOrFinalNewline, // \Z
+    PreviousMatchEnd,        // \G
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -33,15 +59,39 @@ pub(crate) enum Quantifier {
     Range(usize, Option<usize>), // {n,m}
 }
 
+// inline flags such as (?i-i), where None leaves a flag unchanged
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct Flags {
+    pub(crate) case_insensitive: Option<bool>, // i
+    pub(crate) multi_line: Option<bool>,       // m
+    pub(crate) dot_all: Option<bool>,          // s
+    pub(crate) crlf: Option<bool>,             // R
+    pub(crate) unicode: Option<bool>,          // u
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub(crate) enum Unit {
     ImplicitGroup(Vec<Unit>),
-    Group { index: usize, children: Vec<Unit> },
+    Group {
+        index: usize,
+        name: Option<String>,
+        children: Vec<Unit>,
+    },
+    NonCapturingGroup(Vec<Unit>), // (?:a)
+    Lookahead {
+        negative: bool, // (?!a) rather than (?=a)
+        children: Vec<Unit>,
+    },
     CharacterClass(CharacterClass),
     Anchor(Anchor),
-    QuantifiedExpr { expr: Box<Unit>, quantifier: Quantifier },
+    QuantifiedExpr {
+        expr: Box<Unit>,
+        quantifier: Quantifier,
+        lazy: bool, // a*?
+    },
     Alternation(Vec<Unit>), // a|b
     Backreference(usize),   // (a)\1
+    Flags(Flags),           // (?i)
 }
 
 fn fmt_with_indent(u: &Unit, f: &mut fmt::Formatter<'_>, indent: usize) -> fmt::Result {
@@ -54,8 +104,23 @@ fn fmt_with_indent(u: &Unit, f: &mut fmt::Formatter<'_>, indent: usize) -> fmt::
                 fmt_with_indent(child, f, indent + 2)?;
             }
         }
-        Unit::Group { index, children } => {
-            writeln!(f, "{}- Group(index: {})", indent_str, index)?;
+        Unit::Group { index, name, children } => {
+            match name {
+                Some(name) => writeln!(f, "{}- Group(index: {}, name: {})", indent_str, index, name)?,
+                None => writeln!(f, "{}- Group(index: {})", indent_str, index)?,
+            }
+            for child in children {
+                fmt_with_indent(child, f, indent + 2)?;
+            }
+        }
+        Unit::NonCapturingGroup(children) => {
+            writeln!(f, "{}- NonCapturingGroup", indent_str)?;
+            for child in children {
+                fmt_with_indent(child, f, indent + 2)?;
+            }
+        }
+        Unit::Lookahead { negative, children } => {
+            writeln!(f, "{}- Lookahead(negative: {})", indent_str, negative)?;
             for child in children {
                 fmt_with_indent(child, f, indent + 2)?;
             }
@@ -63,8 +128,8 @@ fn fmt_with_indent(u: &Unit, f: &mut fmt::Formatter<'_>, indent: usize) -> fmt::
         Unit::CharacterClass(c) => match c {
             CharacterClass::Char(c) => writeln!(f, "{}- Char({})", indent_str, c)?,
             // CharacterClass::String(s) => writeln!(f, "{}- String(\"{}\")", indent_str, s)?,
-            CharacterClass::Digit => writeln!(f, "{}- DigitClass", indent_str)?,
-            CharacterClass::Word => writeln!(f, "{}- WordClass", indent_str)?,
+            CharacterClass::Shorthand(shorthand) => writeln!(f, "{}- {:?}Class", indent_str, shorthand)?,
+            CharacterClass::Property { negative, property } => writeln!(f, "{}- {:?}(negative: {})", indent_str, property, negative)?,
             CharacterClass::Wildcard => writeln!(f, "{}- Wildcard", indent_str)?,
             CharacterClass::Group { negative, items } => {
                 writeln!(f, "{}- CharacterGroup(negative: {})", indent_str, negative)?;
@@ -72,15 +137,16 @@ fn fmt_with_indent(u: &Unit, f: &mut fmt::Formatter<'_>, indent: usize) -> fmt::
                 for item in items {
                     match item {
                         CharacterGroupItem::Char(c) => writeln!(f, "{}- Char({})", indent_str, c)?,
-                        CharacterGroupItem::Digit => writeln!(f, "{}- DigitClass", indent_str)?,
-                        CharacterGroupItem::Word => writeln!(f, "{}- WordClass", indent_str)?,
+                        CharacterGroupItem::Shorthand(shorthand) => writeln!(f, "{}- {:?}Class", indent_str, shorthand)?,
+                        CharacterGroupItem::Property { negative, property } => writeln!(f, "{}- {:?}(negative: {})", indent_str, property, negative)?,
+                        CharacterGroupItem::Range(from, to) => writeln!(f, "{}- Range({}-{})", indent_str, from, to)?,
                     }
                 }
             }
         },
         Unit::Anchor(a) => writeln!(f, "{}- Anchor({:?})", indent_str, a)?,
-        Unit::QuantifiedExpr { expr, quantifier } => {
-            writeln!(f, "{}- QuantifiedExpr({:?})", indent_str, quantifier)?;
+        Unit::QuantifiedExpr { expr, quantifier, lazy } => {
+            writeln!(f, "{}- QuantifiedExpr({:?}, lazy: {})", indent_str, quantifier, lazy)?;
             fmt_with_indent(expr, f, indent + 2)?;
         }
         Unit::Alternation(children) => {
@@ -90,6 +156,7 @@ fn fmt_with_indent(u: &Unit, f: &mut fmt::Formatter<'_>, indent: usize) -> fmt::
             }
         }
         Unit::Backreference(i) => writeln!(f, "{}- Backreference(index: {})", indent_str, i)?,
+        Unit::Flags(flags) => writeln!(f, "{}- Flags({:?})", indent_str, flags)?,
     }
 
     Ok(())
@@ -101,37 +168,44 @@ impl fmt::Display for Unit {
     }
 }
 
+// what a group is, from the modifier after its '('
+enum GroupKind {
+    Capturing { index: usize, name: Option<String> },
+    NonCapturing(Option<Flags>), // with the flags of (?i:...)
+    Lookahead { negative: bool },
+    Backreference(Unit), // (?P=name)
+    Flags(Flags),        // (?i), which has no contents
+}
+
 pub(crate) struct Parser<'a> {
     iter: Peekable<Chars<'a>>,
+    offset: usize,
     group_index: usize,
+    group_names: HashMap<String, usize>,
+    repetition_limit: usize,
+    nest_limit: usize,
+    depth: usize, // number of groups around the current position
 }
 
 impl Parser<'_> {
-    pub(crate) fn new(pattern: &str) -> Parser {
+    pub(crate) fn new<'a>(pattern: &'a str, config: &Config) -> Parser<'a> {
         Parser {
             iter: pattern.chars().peekable(),
+            offset: 0,
             group_index: 1,
+            group_names: HashMap::new(),
+            repetition_limit: config.repetition_limit,
+            nest_limit: config.nest_limit,
+            depth: 0,
         }
     }
 
     pub(crate) fn parse(&mut self) -> Result<Unit> {
-        let mut units = Vec::new();
-        if self.is_match('^') {
-            units.push(Unit::Anchor(Anchor::StartOfString));
-        }
-
         let expr = self.expression()?;
-        units.push(expr);
-
-        if self.iter.peek().is_some() {
-            return Err(anyhow!("unexpected character: '{}'", self.iter.peek().unwrap()));
-        }
-
-        if units.len() == 1 {
-            return Ok(units[0].clone());
+        if let Some(&c) = self.iter.peek() {
+            return Err(self.error(ErrorKind::UnexpectedCharacter, format!("unexpected character: '{}'", c)));
         }
-
-        Ok(Unit::ImplicitGroup(units))
+        Ok(expr)
     }
 
     fn expression(&mut self) -> Result<Unit> {
@@ -149,13 +223,9 @@ impl Parser<'_> {
         Ok(Unit::Alternation(exprs))
     }
 
+    // an empty subexpression, as in a| or (), matches the empty string
     fn subexpression(&mut self) -> Result<Unit> {
-        let item = self.subexpression_item()?;
-        if item.is_none() {
-            return Err(anyhow!("expected subexpression"));
-        }
-
-        let mut exprs = vec![item.unwrap()];
+        let mut exprs = Vec::new();
         while let Some(e) = self.subexpression_item()? {
             // if let Unit::CharacterClass(CharacterClass::Char(cur)) = e {
             //     if let Unit::CharacterClass(CharacterClass::Char(prev)) = exprs.last().unwrap() {
@@ -195,17 +265,16 @@ impl Parser<'_> {
             return self.group().map(Some);
         }
 
-        let result = self.anchor()?;
-        if result.is_some() {
-            return Ok(result);
+        // a quantified anchor, as in \b+ or ^*, is repeated like any zero-width expression
+        if let Some(anchor) = self.anchor()? {
+            return self.quantified(anchor).map(Some);
         }
 
-        let result = self.character_class()?;
-        if result.is_some() {
-            return Ok(result);
+        if let Some(backreference) = self.backreference()? {
+            return self.quantified(backreference).map(Some);
         }
 
-        let result = self.backreference()?;
+        let result = self.character_class()?;
         if result.is_some() {
             return Ok(result);
         }
@@ -214,27 +283,39 @@ impl Parser<'_> {
     }
 
     fn anchor(&mut self) -> Result<Option<Unit>> {
+        if self.is_match('^') {
+            return Ok(Some(Unit::Anchor(Anchor::StartOfLine)));
+        }
+
         if self.is_match('$') {
-            return Ok(Some(Unit::Anchor(Anchor::EndOfString)));
+            return Ok(Some(Unit::Anchor(Anchor::EndOfLine)));
+        }
+
+        // \b{start} and \b{end} before \b, whose '{' would otherwise be a literal
+        let anchors = [
+            ("\\b{start}", Anchor::StartOfWord),
+            ("\\b{end}", Anchor::EndOfWord),
+            ("\\b", Anchor::WordBoundary),
+            ("\\B", Anchor::NotWordBoundary),
+            ("\\A", Anchor::StartOfText),
+            ("\\z", Anchor::EndOfText),
+            ("\\Z", Anchor::EndOfTextOrFinalNewline),
+            ("\\G", Anchor::PreviousMatchEnd),
+        ];
+        for (s, anchor) in anchors {
+            if self.is_match_str(s) {
+                return Ok(Some(Unit::Anchor(anchor)));
+            }
         }
 
         Ok(None)
     }
 
     fn character_class(&mut self) -> Result<Option<Unit>> {
-        let mut item = self.character_class_item()?;
-        if item.is_none() {
-            return Ok(None);
+        match self.character_class_item()? {
+            Some(item) => self.quantified(item).map(Some),
+            None => Ok(None),
         }
-
-        if let Some(quantifier) = self.quantifier()? {
-            item = Some(Unit::QuantifiedExpr {
-                expr: Box::new(item.unwrap()),
-                quantifier,
-            });
-        }
-
-        Ok(item)
     }
 
     fn character_class_item(&mut self) -> Result<Option<Unit>> {
@@ -248,40 +329,72 @@ impl Parser<'_> {
 
         self.character_group_item().map(|x| match x {
             Some(CharacterGroupItem::Char(c)) => Some(Unit::CharacterClass(CharacterClass::Char(c))),
-            Some(CharacterGroupItem::Digit) => Some(Unit::CharacterClass(CharacterClass::Digit)),
-            Some(CharacterGroupItem::Word) => Some(Unit::CharacterClass(CharacterClass::Word)),
+            Some(CharacterGroupItem::Shorthand(shorthand)) => Some(Unit::CharacterClass(CharacterClass::Shorthand(shorthand))),
+            Some(CharacterGroupItem::Property { negative, property }) => Some(Unit::CharacterClass(CharacterClass::Property { negative, property })),
+            Some(CharacterGroupItem::Range(..)) => unreachable!("ranges are only parsed in character groups"),
             None => None,
         })
     }
 
     fn character_group_item(&mut self) -> Result<Option<CharacterGroupItem>> {
         let mut iter = self.iter.clone();
-        if iter.next() == Some('\\') && iter.next().map_or(false, |x| !x.is_ascii_digit()) {
-            self.iter.next();
-            match self.iter.next().unwrap() {
-                'd' => return Ok(Some(CharacterGroupItem::Digit)),
-                'w' => return Ok(Some(CharacterGroupItem::Word)),
-                c => return Ok(Some(CharacterGroupItem::Char(c))),
+        if iter.next() == Some('\\') && iter.next().is_some_and(|x| !x.is_ascii_digit()) {
+            let offset = self.offset;
+            self.next();
+            let c = self.next().unwrap();
+            if c == 'p' || c == 'P' {
+                return self.property(c == 'P', offset).map(Some);
             }
+            return match shorthand(c) {
+                Some(shorthand) => Ok(Some(CharacterGroupItem::Shorthand(shorthand))),
+                None => Ok(Some(CharacterGroupItem::Char(escaped_char(c)))),
+            };
         }
 
-        if self.iter.peek().map_or(false, |&x| ![']', ')', '|', '\\'].contains(&x)) {
-            let c = self.iter.next().unwrap();
+        if self.iter.peek().is_some_and(|&x| ![']', ')', '|', '\\'].contains(&x)) {
+            let c = self.next().unwrap();
             return Ok(Some(CharacterGroupItem::Char(c)));
         }
 
         Ok(None)
     }
 
+    // the name after \p or \P, either a single letter as in \pL or in braces as in \p{Greek}
+    fn property(&mut self, negative: bool, offset: usize) -> Result<CharacterGroupItem> {
+        let name = match self.next() {
+            Some('{') => {
+                let mut name = String::new();
+                loop {
+                    match self.next() {
+                        Some('}') => break,
+                        Some(c) => name.push(c),
+                        None => return Err(self.error(ErrorKind::InvalidUnicodeProperty, "expected '}'")),
+                    }
+                }
+                name
+            }
+            Some(c) => c.to_string(),
+            None => return Err(self.error(ErrorKind::InvalidUnicodeProperty, "expected a property name")),
+        };
+        match unicode::property(&name) {
+            Some(property) => Ok(CharacterGroupItem::Property { negative, property }),
+            None => Err(Error::new(
+                ErrorKind::InvalidUnicodeProperty,
+                offset,
+                format!("unknown Unicode property: {}", name),
+            )),
+        }
+    }
+
     fn character_group(&mut self) -> Result<Unit> {
         let mut negative_modifier = false;
         if self.is_match('^') {
             negative_modifier = true;
         }
 
-        let item = self.character_group_item()?;
+        let item = self.character_group_range()?;
         if item.is_none() {
-            return Err(anyhow!("expected character group item"));
+            return Err(self.error(ErrorKind::EmptyCharacterGroup, "expected character group item"));
         }
 
         let mut items = vec![item.unwrap()];
@@ -290,12 +403,12 @@ impl Parser<'_> {
                 break;
             }
             if self.iter.peek().is_none() {
-                return Err(anyhow!("expected ]')"));
+                return Err(self.error(ErrorKind::UnclosedCharacterGroup, "expected ']'"));
             }
 
-            let item = self.character_group_item()?;
+            let item = self.character_group_range()?;
             if item.is_none() {
-                return Err(anyhow!("expected character group item"));
+                return Err(self.error(ErrorKind::EmptyCharacterGroup, "expected character group item"));
             }
             items.push(item.unwrap());
         }
@@ -306,44 +419,250 @@ impl Parser<'_> {
         }))
     }
 
+    // a-z, where a '-' that can't be part of a range, as in [-a] or [a-], is a literal
+    fn character_group_range(&mut self) -> Result<Option<CharacterGroupItem>> {
+        let offset = self.offset;
+        let item = self.character_group_item()?;
+        let from = match item {
+            Some(CharacterGroupItem::Char(c)) => c,
+            _ => return Ok(item),
+        };
+
+        let mut iter = self.iter.clone();
+        if iter.next() != Some('-') || matches!(iter.next(), None | Some(']')) {
+            return Ok(item);
+        }
+        self.next();
+
+        let to = match self.character_group_item()? {
+            Some(CharacterGroupItem::Char(c)) => c,
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::InvalidCharacterRange,
+                    offset,
+                    "expected a char at the end of the range",
+                ))
+            }
+        };
+        if to < from {
+            return Err(Error::new(
+                ErrorKind::InvalidCharacterRange,
+                offset,
+                format!("invalid range: '{}-{}'", from, to),
+            ));
+        }
+        Ok(Some(CharacterGroupItem::Range(from, to)))
+    }
+
     fn backreference(&mut self) -> Result<Option<Unit>> {
         let mut iter = self.iter.clone();
-        if iter.next() != Some('\\') || iter.next().map_or(false, |x| !x.is_ascii_digit()) {
+        if iter.next() != Some('\\') {
             return Ok(None);
         }
 
-        self.iter.next();
+        let offset = self.offset;
+        match iter.next() {
+            // \k<name> \k'name' \k{name}
+            Some('k') => {
+                self.next();
+                self.next();
+                let close = match self.next() {
+                    Some('<') => '>',
+                    Some('\'') => '\'',
+                    Some('{') => '}',
+                    _ => return Err(self.error(ErrorKind::InvalidBackreference, "expected '<', ''' or '{' after '\\k'")),
+                };
+                let name = self.name(close)?;
+                return self.named_backreference(&name, offset).map(Some);
+            }
+            Some(d) if d.is_ascii_digit() => {}
+            _ => return Ok(None),
+        }
+
+        self.next();
         let mut digits = String::new();
         while let Some(&d) = self.iter.peek() {
             if !d.is_ascii_digit() {
                 break;
             }
             digits.push(d);
-            self.iter.next();
-        }
-        let index = digits.parse::<usize>()?;
-        if index >= self.group_index {
-            return Err(anyhow!("invalid backreference: {}", index));
-        }
+            self.next();
+        }
+        let index = match digits.parse::<usize>() {
+            Ok(index) if index < self.group_index => index,
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::InvalidBackreference,
+                    offset,
+                    format!("invalid backreference: {}", digits),
+                ))
+            }
+        };
         Ok(Some(Unit::Backreference(index)))
     }
 
+    fn named_backreference(&self, name: &str, offset: usize) -> Result<Unit> {
+        match self.group_names.get(name) {
+            Some(&index) => Ok(Unit::Backreference(index)),
+            None => Err(Error::new(
+                ErrorKind::InvalidBackreference,
+                offset,
+                format!("unknown group name: {}", name),
+            )),
+        }
+    }
+
+    // every group is parsed by a recursive call, so its depth is limited to keep the stack from
+    // overflowing, and the modifier is parsed apart to keep the recursive frames small
     fn group(&mut self) -> Result<Unit> {
+        let offset = self.offset - 1;
+        let kind = match self.group_kind(offset)? {
+            GroupKind::Backreference(backreference) => return self.quantified(backreference),
+            GroupKind::Flags(flags) => return Ok(Unit::Flags(flags)),
+            kind => kind,
+        };
+
+        if self.depth == self.nest_limit {
+            let message = format!("groups nested more than {} deep", self.nest_limit);
+            return Err(Error::new(ErrorKind::NestLimitExceeded, offset, message));
+        }
+        self.depth += 1;
+        let expr = self.expression()?;
+        self.depth -= 1;
+        self.consume(')', ErrorKind::UnclosedGroup)?;
+
+        let group = match kind {
+            GroupKind::Capturing { index, name } => Unit::Group {
+                index,
+                name,
+                children: vec![expr],
+            },
+            GroupKind::NonCapturing(None) => Unit::NonCapturingGroup(vec![expr]),
+            // (?i:...) is a non-capturing group with the flags applied to its contents only
+            GroupKind::NonCapturing(Some(flags)) => Unit::NonCapturingGroup(vec![Unit::Flags(flags), expr]),
+            // as a lookahead matches nothing, (?=a)? makes it optional and (?=a)+ is the same as (?=a)
+            GroupKind::Lookahead { negative } => Unit::Lookahead {
+                negative,
+                children: vec![expr],
+            },
+            GroupKind::Backreference(_) | GroupKind::Flags(_) => unreachable!(),
+        };
+        self.quantified(group)
+    }
+
+    // the modifier after the '(' at offset, up to the contents of the group
+    fn group_kind(&mut self, offset: usize) -> Result<GroupKind> {
+        if self.is_match_str("?P=") {
+            // (?P=name) is a backreference rather than a group
+            let name = self.name(')')?;
+            return self.named_backreference(&name, offset).map(GroupKind::Backreference);
+        }
+
+        if self.is_match_str("?:") {
+            return Ok(GroupKind::NonCapturing(None));
+        }
+
+        for (s, negative) in [("?=", false), ("?!", true)] {
+            if self.is_match_str(s) {
+                return Ok(GroupKind::Lookahead { negative });
+            }
+        }
+
+        let mut iter = self.iter.clone();
+        if iter.next() == Some('?') && iter.next().is_some_and(is_flag) {
+            self.next();
+            let flags = self.flags()?;
+            if self.is_match(')') {
+                return Ok(GroupKind::Flags(flags));
+            }
+            self.consume(':', ErrorKind::UnclosedGroup)?;
+            return Ok(GroupKind::NonCapturing(Some(flags)));
+        }
+
         let index = self.group_index;
         self.group_index += 1;
 
-        let expr = self.expression()?;
-        self.consume(')')?;
-        let group = Unit::Group { index, children: vec![expr] };
+        let mut name = None;
+        if self.is_match('?') {
+            name = Some(self.group_name(index)?);
+        }
+        Ok(GroupKind::Capturing { index, name })
+    }
 
-        if let Ok(Some(quantifier)) = self.quantifier() {
-            return Ok(Unit::QuantifiedExpr {
-                expr: Box::new(group),
-                quantifier,
-            });
+    // imsRu-imsRu, up to the closing ')' or ':'
+    fn flags(&mut self) -> Result<Flags> {
+        let mut flags = Flags::default();
+        let mut enabled = true;
+        while let Some(&c) = self.iter.peek() {
+            match c {
+                ')' | ':' => break,
+                '-' if enabled => enabled = false,
+                'i' => flags.case_insensitive = Some(enabled),
+                'm' => flags.multi_line = Some(enabled),
+                's' => flags.dot_all = Some(enabled),
+                'R' => flags.crlf = Some(enabled),
+                'u' => flags.unicode = Some(enabled),
+                _ => return Err(self.error(ErrorKind::InvalidFlag, format!("unknown flag: '{}'", c))),
+            }
+            self.next();
+        }
+        Ok(flags)
+    }
+
+    // (?<name>...) (?'name'...) (?P<name>...)
+    fn group_name(&mut self, index: usize) -> Result<String> {
+        let close = match self.iter.peek() {
+            Some('<') => '>',
+            Some('\'') => '\'',
+            Some('P') => {
+                self.next();
+                if self.iter.peek() != Some(&'<') {
+                    return Err(self.error(ErrorKind::InvalidGroupModifier, "expected '<' after '(?P'"));
+                }
+                '>'
+            }
+            _ => return Err(self.error(ErrorKind::InvalidGroupModifier, "unknown group modifier")),
+        };
+        self.next();
+
+        let offset = self.offset;
+        let name = self.name(close)?;
+        if self.group_names.insert(name.clone(), index).is_some() {
+            return Err(Error::new(
+                ErrorKind::DuplicateGroupName,
+                offset,
+                format!("duplicate group name: {}", name),
+            ));
+        }
+        Ok(name)
+    }
+
+    fn name(&mut self, close: char) -> Result<String> {
+        let offset = self.offset;
+        let mut name = String::new();
+        while let Some(c) = self.next() {
+            if c == close {
+                let is_valid = name.chars().next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+                    && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+                if !is_valid {
+                    return Err(Error::new(ErrorKind::InvalidGroupName, offset, format!("invalid group name: '{}'", name)));
+                }
+                return Ok(name);
+            }
+            name.push(c);
         }
+        Err(self.error(ErrorKind::InvalidGroupName, format!("expected '{}'", close)))
+    }
 
-        Ok(group)
+    fn quantified(&mut self, unit: Unit) -> Result<Unit> {
+        match self.quantifier()? {
+            Some(quantifier) => Ok(Unit::QuantifiedExpr {
+                expr: Box::new(unit),
+                quantifier,
+                lazy: self.is_match('?'),
+            }),
+            None => Ok(unit),
+        }
     }
 
     fn quantifier(&mut self) -> Result<Option<Quantifier>> {
@@ -359,32 +678,138 @@ impl Parser<'_> {
             return Ok(Some(Quantifier::ZeroOrOne));
         }
 
-        // TODO: range
+        if self.iter.peek() == Some(&'{') {
+            return self.range_quantifier();
+        }
 
         Ok(None)
     }
 
+    // {n} {n,} {n,m}, or None if the '{' doesn't start one and is a literal
+    fn range_quantifier(&mut self) -> Result<Option<Quantifier>> {
+        let mut iter = self.iter.clone();
+        iter.next();
+        let mut quantifier = String::new();
+        loop {
+            match iter.next() {
+                Some('}') => break,
+                Some(c) if c.is_ascii_digit() || c == ',' => quantifier.push(c),
+                _ => return Ok(None),
+            }
+        }
+
+        let (min, max) = match quantifier.split_once(',') {
+            Some((min, max)) => (min, Some(max)),
+            None => (quantifier.as_str(), None),
+        };
+        if min.is_empty() || max.is_some_and(|max| max.contains(',')) {
+            return Ok(None);
+        }
+
+        let offset = self.offset;
+        for _ in 0..quantifier.len() + 2 {
+            self.next();
+        }
+
+        let min = self.repetition_count(min, offset)?;
+        let quantifier = match max {
+            None => Quantifier::Exact(min),
+            Some("") => Quantifier::Range(min, None),
+            Some(max) => {
+                let max = self.repetition_count(max, offset)?;
+                if min > max {
+                    return Err(Error::new(
+                        ErrorKind::InvalidRepetition,
+                        offset,
+                        format!("invalid repetition: {{{},{}}}", min, max),
+                    ));
+                }
+                Quantifier::Range(min, Some(max))
+            }
+        };
+        Ok(Some(quantifier))
+    }
+
+    fn repetition_count(&self, digits: &str, offset: usize) -> Result<usize> {
+        match digits.parse::<usize>() {
+            Ok(n) if n <= self.repetition_limit => Ok(n),
+            _ => Err(Error::new(
+                ErrorKind::RepetitionLimitExceeded,
+                offset,
+                format!("repetition count exceeds the limit of {}", self.repetition_limit),
+            )),
+        }
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.iter.next();
+        if c.is_some() {
+            self.offset += 1;
+        }
+        c
+    }
+
     fn is_match(&mut self, c: char) -> bool {
         match self.iter.peek() {
             Some(&ch) if ch == c => {
-                self.iter.next();
+                self.next();
                 true
             }
             _ => false,
         }
     }
 
-    fn consume(&mut self, c: char) -> Result<()> {
-        if let Some(&ch) = self.iter.peek() {
-            if ch == c {
-                self.iter.next();
-                return Ok(());
-            }
+    fn is_match_str(&mut self, s: &str) -> bool {
+        if !self.iter.clone().take(s.chars().count()).eq(s.chars()) {
+            return false;
+        }
+        for _ in s.chars() {
+            self.next();
+        }
+        true
+    }
+
+    fn consume(&mut self, c: char, kind: ErrorKind) -> Result<()> {
+        if self.is_match(c) {
+            return Ok(());
         }
-        Err(anyhow!("expected '{}'", c))
+        Err(self.error(kind, format!("expected '{}'", c)))
+    }
+
+    fn error(&self, kind: ErrorKind, message: impl Into<String>) -> Error {
+        Error::new(kind, self.offset, message)
     }
 }
 
+fn shorthand(c: char) -> Option<Shorthand> {
+    match c {
+        'd' => Some(Shorthand::Digit),
+        'D' => Some(Shorthand::NotDigit),
+        'w' => Some(Shorthand::Word),
+        'W' => Some(Shorthand::NotWord),
+        's' => Some(Shorthand::Space),
+        'S' => Some(Shorthand::NotSpace),
+        'h' => Some(Shorthand::HorizontalSpace),
+        'v' => Some(Shorthand::VerticalSpace),
+        _ => None,
+    }
+}
+
+// \n \r \t \f, any other escaped char stands for itself
+fn escaped_char(c: char) -> char {
+    match c {
+        'n' => '\n',
+        'r' => '\r',
+        't' => '\t',
+        'f' => '\x0C',
+        _ => c,
+    }
+}
+
+fn is_flag(c: char) -> bool {
+    matches!(c, '-' | 'i' | 'm' | 's' | 'R' | 'u')
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -392,7 +817,7 @@ mod tests {
     #[test]
     fn parse_regex() {
         // let mut parser = Parser::new("('(cat) and \\2') is the same as \\1");
-        let mut parser = Parser::new("the ((red|blue) pill)$");
+        let mut parser = Parser::new("the ((red|blue) pill)$", &Config::default());
         let result = parser.parse();
         println!("{}", result.unwrap());
     }
@@ -0,0 +1,237 @@
+use std::collections::HashSet;
+use std::mem;
+
+use crate::program::{self, Program, State, StateId};
+use crate::unicode;
+
+// Runs every path through the program in lockstep, one char at a time, so a search takes
+// O(n * m) time for a text of n chars and a program of m states. Threads are kept in priority
+// order, and the first one to reach the match state wins, which gives the same match as the
+// backtracking matcher. Backreferences need the backtracking matcher.
+//
+// A folded state may match several chars, or one char against several of its own, as 'ß' against
+// "ss", so its threads also move one char at a time and remember how much of the folding they
+// matched until they reach the end of it.
+//
+// A path reaching a state that a path of higher priority already reached at the same position is
+// dropped, as it can only lead to the same threads. That doesn't hold for the progress states of
+// loops that started an iteration at this position, so until the next char the states are told
+// apart by which loops did.
+pub(crate) struct PikeVM<'r, 't> {
+    program: &'r Program,
+    text: &'t str,
+    previous_end: usize, // where \G matches
+}
+
+// the scratch space of a search, reused by the next one
+#[derive(Default)]
+pub(crate) struct Cache {
+    current: Threads,
+    next: Threads,
+    stack: Vec<Frame>,
+    slots: Vec<Option<usize>>, // the slots of the thread being added
+    folded: String,            // the full case folding of the current char
+}
+
+// the threads alive at one position, at most one per state
+#[derive(Default)]
+struct Threads {
+    states: Vec<StateId>,      // the consuming and match states reached, in priority order
+    folded: Vec<usize>,        // for each state, the byte length of its folding matched so far
+    slots: Vec<Option<usize>>, // the slots of each thread, Program::slots_len() per state
+    visited: Vec<bool>,        // indexed by state id, including the states that only lead to others
+    visited_ids: Vec<StateId>,
+    visited_in_iterations: HashSet<(StateId, Vec<usize>)>, // with the loops that started an iteration here
+    visited_in_folded: HashSet<(StateId, usize)>,          // folded states part way through their folding
+}
+
+enum Frame {
+    Explore(StateId),
+    RestoreSlot(usize, Option<usize>), // undo a save once the states after it are added
+}
+
+impl Threads {
+    fn reset(&mut self, len: usize) {
+        self.clear();
+        self.visited.resize(len, false);
+    }
+
+    // iterations lists the loops whose progress state would fail at this position
+    fn visit(&mut self, state: StateId, iterations: Vec<usize>) -> bool {
+        if !iterations.is_empty() {
+            return self.visited_in_iterations.insert((state, iterations));
+        }
+        if self.visited[state] {
+            return false;
+        }
+        self.visited[state] = true;
+        self.visited_ids.push(state);
+        true
+    }
+
+    // keeps a thread part way through the folding of a folded state for the next char
+    fn push_folded(&mut self, state: StateId, folded: usize, slots: &[Option<usize>]) {
+        if self.visited_in_folded.insert((state, folded)) {
+            self.states.push(state);
+            self.folded.push(folded);
+            self.slots.extend_from_slice(slots);
+        }
+    }
+
+    fn clear(&mut self) {
+        for &state in &self.visited_ids {
+            self.visited[state] = false;
+        }
+        self.visited_ids.clear();
+        self.visited_in_iterations.clear();
+        self.visited_in_folded.clear();
+        self.states.clear();
+        self.folded.clear();
+        self.slots.clear();
+    }
+}
+
+impl<'r, 't> PikeVM<'r, 't> {
+    pub(crate) fn new(program: &'r Program, text: &'t str, previous_end: usize) -> PikeVM<'r, 't> {
+        PikeVM { program, text, previous_end }
+    }
+
+    // returns the capture slots of the leftmost match starting at or after start, slots 0 and 1
+    // being the whole match; with earliest, stops at the first position where any match ends
+    pub(crate) fn search(&self, cache: &mut Cache, start: usize, earliest: bool) -> Option<Vec<Option<usize>>> {
+        let Cache {
+            current,
+            next,
+            stack,
+            slots: scratch,
+            folded: folded_char,
+        } = cache;
+        let slots_len = self.program.slots_len();
+        current.reset(self.program.states.len());
+        next.reset(self.program.states.len());
+        scratch.resize(slots_len, None);
+        let mut matched = None;
+
+        let mut at = start;
+        loop {
+            // until a match is found, a new one may start at every position, with the lowest priority
+            if matched.is_none() {
+                scratch.fill(None);
+                self.add_thread(current, stack, scratch, at, self.program.start);
+            } else if current.states.is_empty() {
+                break;
+            }
+
+            let threads = current.states.iter().zip(&current.folded).zip(current.slots.chunks(slots_len));
+            for ((&id, &folded), slots) in threads {
+                let state = &self.program.states[id];
+                if let State::Match = state {
+                    if earliest {
+                        return Some(slots.to_vec());
+                    }
+                    // threads of lower priority can't win anymore
+                    matched = Some(slots.to_vec());
+                    break;
+                }
+
+                if let State::Folded {
+                    folded: string,
+                    next: target,
+                } = state
+                {
+                    let Some(ch) = program::char_at(self.text, at) else {
+                        continue;
+                    };
+                    folded_char.clear();
+                    unicode::full_fold(ch, folded_char);
+                    if !string[folded..].starts_with(folded_char.as_str()) {
+                        continue;
+                    }
+                    let folded = folded + folded_char.len();
+                    if folded < string.len() {
+                        next.push_folded(id, folded, slots);
+                    } else {
+                        scratch.copy_from_slice(slots);
+                        self.add_thread(next, stack, scratch, at + ch.len_utf8(), *target);
+                    }
+                } else if let Some(n) = program::consume(state, self.text, at, slots) {
+                    if let State::Char { next: target, .. } | State::Class { next: target, .. } = state {
+                        scratch.copy_from_slice(slots);
+                        self.add_thread(next, stack, scratch, at + n, *target);
+                    }
+                }
+            }
+
+            match program::char_at(self.text, at) {
+                Some(ch) => at += ch.len_utf8(),
+                None => break,
+            }
+            mem::swap(current, next);
+            next.clear();
+        }
+
+        matched
+    }
+
+    // adds a thread for the state, following the empty transitions in the order the
+    // backtracking matcher would try them; slots are those of the thread so far
+    fn add_thread(&self, threads: &mut Threads, stack: &mut Vec<Frame>, slots: &mut [Option<usize>], at: usize, state: StateId) {
+        stack.push(Frame::Explore(state));
+        while let Some(frame) = stack.pop() {
+            let state = match frame {
+                Frame::Explore(state) => state,
+                Frame::RestoreSlot(slot, value) => {
+                    slots[slot] = value;
+                    continue;
+                }
+            };
+            // after consuming a char, every progress state passes, so those states only need one thread
+            let iterations = match &self.program.states[state] {
+                State::Char { .. } | State::Folded { .. } | State::Class { .. } | State::Match => Vec::new(),
+                _ => self.iterations(slots, at),
+            };
+            if !threads.visit(state, iterations) {
+                continue;
+            }
+
+            match &self.program.states[state] {
+                State::Split(targets) => {
+                    // pushed in reverse so that the first target is popped first
+                    stack.extend(targets.iter().rev().map(|&target| Frame::Explore(target)));
+                }
+                State::Save { slot, next } => {
+                    stack.push(Frame::RestoreSlot(*slot, slots[*slot]));
+                    slots[*slot] = Some(at);
+                    stack.push(Frame::Explore(*next));
+                }
+                State::Assertion { assertion, next } => {
+                    if assertion.matches(self.text, at, self.previous_end) {
+                        stack.push(Frame::Explore(*next));
+                    }
+                }
+                State::Progress { slot, next } => {
+                    if slots[*slot].is_none_or(|start| at > start) {
+                        stack.push(Frame::Explore(*next));
+                    }
+                }
+                State::Char { .. } | State::Folded { .. } | State::Class { .. } | State::Match => {
+                    threads.states.push(state);
+                    threads.folded.push(0);
+                    threads.slots.extend_from_slice(slots);
+                }
+                State::Backref { .. } | State::Lookahead { .. } | State::LookaheadEnd => {
+                    unreachable!("requires backtracking")
+                }
+            }
+        }
+    }
+
+    // the loops that started an iteration at this position
+    fn iterations(&self, slots: &[Option<usize>], at: usize) -> Vec<usize> {
+        let loops = &slots[self.program.capture_slots_len()..];
+        if !loops.contains(&Some(at)) {
+            return Vec::new();
+        }
+        (0..loops.len()).filter(|&i| loops[i] == Some(at)).collect()
+    }
+}
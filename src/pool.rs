@@ -0,0 +1,60 @@
+use std::ops::{Deref, DerefMut};
+use std::sync::{Mutex, MutexGuard};
+
+// Values reused across searches, so that threads sharing a regex don't allocate scratch space
+// on every search or wait on each other while they search.
+pub(crate) struct Pool<T> {
+    stack: Mutex<Vec<Box<T>>>,
+}
+
+impl<T> Pool<T> {
+    pub(crate) fn new() -> Pool<T> {
+        Pool {
+            stack: Mutex::new(Vec::new()),
+        }
+    }
+
+    // a poisoned pool only means that a search panicked, the values in it are still fine
+    fn lock(&self) -> MutexGuard<'_, Vec<Box<T>>> {
+        self.stack.lock().unwrap_or_else(|error| error.into_inner())
+    }
+}
+
+impl<T: Default> Pool<T> {
+    // takes a value out of the pool, or creates one if they are all in use
+    pub(crate) fn get(&self) -> PoolGuard<'_, T> {
+        let value = self.lock().pop().unwrap_or_default();
+        PoolGuard {
+            pool: self,
+            value: Some(value),
+        }
+    }
+}
+
+// gives the value back to the pool when dropped
+pub(crate) struct PoolGuard<'p, T> {
+    pool: &'p Pool<T>,
+    value: Option<Box<T>>,
+}
+
+impl<T> Deref for PoolGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.value.as_ref().unwrap()
+    }
+}
+
+impl<T> DerefMut for PoolGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.value.as_mut().unwrap()
+    }
+}
+
+impl<T> Drop for PoolGuard<'_, T> {
+    fn drop(&mut self) {
+        if let Some(value) = self.value.take() {
+            self.pool.lock().push(value);
+        }
+    }
+}
@@ -0,0 +1,317 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::parser::{CharacterGroupItem, Shorthand};
+use crate::unicode::{self, Property};
+
+pub(crate) type StateId = usize; // index of a state in Program::states
+
+// A compiled regex: an NFA whose states are interpreted by the matchers.
+pub(crate) struct Program {
+    pub(crate) states: Vec<State>,
+    pub(crate) start: StateId,
+    pub(crate) capture_names: Vec<Option<String>>, // one entry per group, including the implicit group 0
+    pub(crate) group_names: Arc<HashMap<String, usize>>, // map a group name to its index
+    pub(crate) loops: usize,                       // number of loops, each with a slot after the capture slots
+    pub(crate) requires_backtracking: bool,        // backreferences and lookaheads can't run on the pike vm
+}
+
+impl Program {
+    // two slots per group, for the byte offsets where it starts and ends
+    pub(crate) fn capture_slots_len(&self) -> usize {
+        2 * self.capture_names.len()
+    }
+
+    pub(crate) fn slots_len(&self) -> usize {
+        self.capture_slots_len() + self.loops
+    }
+}
+
+pub(crate) enum State {
+    Char {
+        c: char,
+        case_insensitive: bool,
+        next: StateId,
+    },
+    Folded {
+        folded: String,
+        next: StateId,
+    }, // chars whose full case folding is `folded`, e.g. 'ß' against "SS"
+    Class {
+        class: Class,
+        next: StateId,
+    },
+    Assertion {
+        assertion: Assertion,
+        next: StateId,
+    },
+    Split(Vec<StateId>), // empty transitions, in the order they should be tried
+    Save {
+        slot: usize,
+        next: StateId,
+    },
+    Progress {
+        slot: usize,
+        next: StateId,
+    }, // only continues if the text advanced past the offset saved in slot
+    Backref {
+        index: usize,
+        case_insensitive: bool,
+        full_case_folding: bool,
+        next: StateId,
+    },
+    Lookahead {
+        negative: bool,
+        start: StateId,
+        next: StateId,
+    }, // continues if the states from start reach LookaheadEnd
+    LookaheadEnd,
+    Match,
+}
+
+#[derive(Debug)]
+pub(crate) enum Class {
+    Shorthand {
+        shorthand: Shorthand,
+        unicode: bool,
+    },
+    Property {
+        negative: bool,
+        property: Property,
+        case_insensitive: bool,
+    },
+    Any,
+    AnyExceptLineTerminator(LineTerminator), // . without dot-all mode
+    Group {
+        negative: bool,
+        items: Vec<CharacterGroupItem>,
+        case_insensitive: bool,
+        unicode: bool,
+    },
+}
+
+#[derive(Debug)]
+pub(crate) enum Assertion {
+    StartOfLine(LineTerminator),
+    EndOfLine(LineTerminator),
+    WordBoundary { unicode: bool },
+    NotWordBoundary { unicode: bool },
+    StartOfWord { unicode: bool },
+    EndOfWord { unicode: bool },
+    StartOfText,
+    EndOfText,
+    EndOfTextOrFinalNewline,
+    PreviousMatchEnd,
+}
+
+// what ends a line for ., ^ and $: a single char, '\n' by default, or "\r", "\n" and "\r\n" in CRLF mode
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum LineTerminator {
+    Char(char),
+    Crlf,
+}
+
+impl LineTerminator {
+    fn matches(&self, ch: char) -> bool {
+        match self {
+            LineTerminator::Char(c) => ch == *c,
+            LineTerminator::Crlf => ch == '\r' || ch == '\n',
+        }
+    }
+}
+
+impl Class {
+    pub(crate) fn matches(&self, ch: char) -> bool {
+        match self {
+            Class::Shorthand { shorthand, unicode } => shorthand.matches(ch, *unicode),
+            Class::Property {
+                negative,
+                property,
+                case_insensitive,
+            } => property.matches(ch, *case_insensitive) != *negative,
+            Class::Any => true,
+            Class::AnyExceptLineTerminator(terminator) => !terminator.matches(ch),
+            Class::Group {
+                negative,
+                items,
+                case_insensitive,
+                unicode,
+            } => {
+                let result = items.iter().any(|item| match item {
+                    CharacterGroupItem::Char(c) => *c == ch || *case_insensitive && unicode::eq_ignore_case(*c, ch),
+                    CharacterGroupItem::Shorthand(shorthand) => shorthand.matches(ch, *unicode),
+                    CharacterGroupItem::Property { negative, property } => property.matches(ch, *case_insensitive) != *negative,
+                    CharacterGroupItem::Range(from, to) => {
+                        let range = *from..=*to;
+                        range.contains(&ch) || *case_insensitive && unicode::case_orbit(ch).iter().any(|c| range.contains(c))
+                    }
+                });
+                result != *negative
+            }
+        }
+    }
+}
+
+impl Assertion {
+    // previous_end is where the previous match of an iteration ended, or 0 for the first search
+    pub(crate) fn matches(&self, text: &str, at: usize, previous_end: usize) -> bool {
+        // whether the chars on each side of at are word chars, the start and end of the text not being ones
+        let word = |unicode| {
+            let before = text[..at].chars().next_back().is_some_and(|ch| is_word(ch, unicode));
+            let after = char_at(text, at).is_some_and(|ch| is_word(ch, unicode));
+            (before, after)
+        };
+        match self {
+            // with crlf, a line ends at "\r", "\n" or "\r\n", but not between the "\r" and the "\n"
+            Assertion::StartOfLine(terminator) => match (text[..at].chars().next_back(), terminator) {
+                (None, _) => true,
+                (Some(ch), LineTerminator::Char(c)) => ch == *c,
+                (Some('\n'), LineTerminator::Crlf) => true,
+                (Some('\r'), LineTerminator::Crlf) => char_at(text, at) != Some('\n'),
+                (Some(_), LineTerminator::Crlf) => false,
+            },
+            Assertion::EndOfLine(terminator) => match (char_at(text, at), terminator) {
+                (None, _) => true,
+                (Some(ch), LineTerminator::Char(c)) => ch == *c,
+                (Some('\r'), LineTerminator::Crlf) => true,
+                (Some('\n'), LineTerminator::Crlf) => !text[..at].ends_with('\r'),
+                (Some(_), LineTerminator::Crlf) => false,
+            },
+            Assertion::WordBoundary { unicode } => {
+                let (before, after) = word(*unicode);
+                before != after
+            }
+            Assertion::NotWordBoundary { unicode } => {
+                let (before, after) = word(*unicode);
+                before == after
+            }
+            Assertion::StartOfWord { unicode } => word(*unicode) == (false, true),
+            Assertion::EndOfWord { unicode } => word(*unicode) == (true, false),
+            Assertion::StartOfText => at == 0,
+            Assertion::EndOfText => at == text.len(),
+            Assertion::EndOfTextOrFinalNewline => at == text.len() || at + 1 == text.len() && text.ends_with('\n'),
+            Assertion::PreviousMatchEnd => at == previous_end,
+        }
+    }
+}
+
+impl Shorthand {
+    // without unicode, \d, \w and \s only match ASCII chars
+    pub(crate) fn matches(&self, ch: char, unicode: bool) -> bool {
+        match self {
+            Shorthand::Digit => is_digit(ch, unicode),
+            Shorthand::NotDigit => !is_digit(ch, unicode),
+            Shorthand::Word => is_word(ch, unicode),
+            Shorthand::NotWord => !is_word(ch, unicode),
+            Shorthand::Space => is_space(ch, unicode),
+            Shorthand::NotSpace => !is_space(ch, unicode),
+            // as in Perl, \h and \v include the Unicode spaces and line breaks
+            Shorthand::HorizontalSpace => matches!(
+                ch,
+                '\t' | ' ' | '\u{A0}' | '\u{1680}' | '\u{180E}' | '\u{2000}'..='\u{200A}' | '\u{202F}' | '\u{205F}' | '\u{3000}'
+            ),
+            Shorthand::VerticalSpace => matches!(ch, '\n'..='\r' | '\u{85}' | '\u{2028}' | '\u{2029}'),
+        }
+    }
+}
+
+impl Property {
+    // case insensitively, \p{Lu} matches the lowercase letters too
+    fn matches(&self, ch: char, case_insensitive: bool) -> bool {
+        self.contains(ch) || case_insensitive && unicode::case_orbit(ch).iter().any(|&c| self.contains(c))
+    }
+}
+
+fn is_digit(ch: char, unicode: bool) -> bool {
+    match ch.is_ascii() || !unicode {
+        true => ch.is_ascii_digit(),
+        false => unicode::is_digit(ch),
+    }
+}
+
+fn is_word(ch: char, unicode: bool) -> bool {
+    match ch.is_ascii() || !unicode {
+        true => ch.is_ascii_alphanumeric() || ch == '_',
+        false => unicode::is_word(ch),
+    }
+}
+
+// unlike char::is_ascii_whitespace, this includes the vertical tab
+fn is_space(ch: char, unicode: bool) -> bool {
+    match ch.is_ascii() || !unicode {
+        true => matches!(ch, ' ' | '\t'..='\r'),
+        false => unicode::is_space(ch),
+    }
+}
+
+pub(crate) fn char_at(text: &str, at: usize) -> Option<char> {
+    text[at..].chars().next()
+}
+
+// returns the length of the text matched by a consuming state at the byte offset at,
+// or None if it doesn't match there; slots are only needed by backreferences
+pub(crate) fn consume(state: &State, text: &str, at: usize, slots: &[Option<usize>]) -> Option<usize> {
+    match state {
+        State::Char { c, case_insensitive, .. } => {
+            let ch = char_at(text, at)?;
+            (ch == *c || *case_insensitive && unicode::eq_ignore_case(ch, *c)).then(|| ch.len_utf8())
+        }
+        State::Class { class, .. } => {
+            let ch = char_at(text, at)?;
+            class.matches(ch).then(|| ch.len_utf8())
+        }
+        State::Folded { folded, .. } => unicode::prefix_len_full_fold(&text[at..], folded),
+        State::Backref {
+            index,
+            case_insensitive,
+            full_case_folding,
+            ..
+        } => {
+            let group = match (slots[2 * index], slots[2 * index + 1]) {
+                (Some(from), Some(to)) if from <= to => &text[from..to],
+                _ => return None,
+            };
+            let substring = &text[at..];
+            if substring.starts_with(group) {
+                return Some(group.len());
+            }
+            if *case_insensitive && *full_case_folding {
+                let mut folded = String::new();
+                group.chars().for_each(|c| unicode::full_fold(c, &mut folded));
+                return unicode::prefix_len_full_fold(substring, &folded);
+            }
+            if *case_insensitive {
+                return unicode::prefix_len_ignore_case(substring, group);
+            }
+            None
+        }
+        _ => None,
+    }
+}
+
+impl fmt::Display for Program {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Program: start {}", self.start)?;
+        for (id, state) in self.states.iter().enumerate() {
+            match state {
+                State::Char { c, case_insensitive, next } => {
+                    writeln!(f, "{} --- '{}'{} --> {}", id, c, if *case_insensitive { " (i)" } else { "" }, next)?
+                }
+                State::Folded { folded, next } => writeln!(f, "{} --- \"{}\" --> {}", id, folded, next)?,
+                State::Class { class, next } => writeln!(f, "{} --- {:?} --> {}", id, class, next)?,
+                State::Assertion { assertion, next } => writeln!(f, "{} --- {:?} --> {}", id, assertion, next)?,
+                State::Split(targets) => writeln!(f, "{} --- split --> {:?}", id, targets)?,
+                State::Save { slot, next } => writeln!(f, "{} --- save {} --> {}", id, slot, next)?,
+                State::Progress { slot, next } => writeln!(f, "{} --- progress {} --> {}", id, slot, next)?,
+                State::Backref { index, next, .. } => writeln!(f, "{} --- \\{} --> {}", id, index, next)?,
+                State::Lookahead { negative, start, next } => {
+                    writeln!(f, "{} --- {} {} --> {}", id, if *negative { "?!" } else { "?=" }, start, next)?
+                }
+                State::LookaheadEnd => writeln!(f, "{} lookahead end", id)?,
+                State::Match => writeln!(f, "{} match", id)?,
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,198 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::unicode_tables::binary_property::{BINARY_PROPERTY, BINARY_PROPERTY_ALIASES};
+use crate::unicode_tables::case_folding::{CASE_ORBITS, FULL_CASE_FOLDING, SIMPLE_CASE_FOLDING};
+use crate::unicode_tables::general_category::{GENERAL_CATEGORY, GENERAL_CATEGORY_ALIASES};
+use crate::unicode_tables::script::{SCRIPT, SCRIPT_ALIASES};
+
+type Ranges = &'static [(char, char)];
+
+// the chars of a Unicode property such as \p{Lu}, \p{Greek} or \p{Alphabetic}
+#[derive(Clone, PartialEq)]
+pub(crate) struct Property {
+    name: String,
+    tables: Vec<Ranges>, // sorted ranges, a char has the property if it is in any of them
+}
+
+impl Property {
+    pub(crate) fn contains(&self, ch: char) -> bool {
+        self.tables.iter().any(|ranges| in_ranges(ranges, ch))
+    }
+}
+
+// the tables are too long to print
+impl fmt::Debug for Property {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Property({})", self.name)
+    }
+}
+
+// looks up a general category, a script or a binary property, by name or as in gc=Lu and
+// Script=Greek; names are compared ignoring case, spaces, '_' and '-'
+pub(crate) fn property(name: &str) -> Option<Property> {
+    let tables = match name.split_once('=') {
+        Some((key, value)) => match normalize(key).as_str() {
+            "gc" | "generalcategory" => general_category(value),
+            "sc" | "script" => script(value),
+            _ => None,
+        },
+        None => general_category(name)
+            .or_else(|| script(name))
+            .or_else(|| lookup(BINARY_PROPERTY, BINARY_PROPERTY_ALIASES, name).map(|ranges| vec![ranges]))
+            .or_else(|| match normalize(name).as_str() {
+                "any" => Some(vec![&[('\0', char::MAX)][..]]),
+                "ascii" => Some(vec![&[('\0', '\x7F')][..]]),
+                _ => None,
+            }),
+    }?;
+    Some(Property {
+        name: name.to_string(),
+        tables,
+    })
+}
+
+// a one-letter category such as L is the union of the categories starting with that letter,
+// and LC is the union of Lu, Ll and Lt
+fn general_category(name: &str) -> Option<Vec<Ranges>> {
+    let category = alias(GENERAL_CATEGORY_ALIASES, name)?;
+    let tables = GENERAL_CATEGORY.iter().filter(|(name, _)| match category {
+        "LC" => matches!(*name, "Lu" | "Ll" | "Lt"),
+        _ => name.starts_with(category),
+    });
+    Some(tables.map(|&(_, ranges)| ranges).collect())
+}
+
+fn script(name: &str) -> Option<Vec<Ranges>> {
+    lookup(SCRIPT, SCRIPT_ALIASES, name).map(|ranges| vec![ranges])
+}
+
+fn lookup(table: &[(&str, Ranges)], aliases: &[(&str, &'static str)], name: &str) -> Option<Ranges> {
+    let name = alias(aliases, name)?;
+    Some(self::table(table, name))
+}
+
+fn alias(aliases: &[(&str, &'static str)], name: &str) -> Option<&'static str> {
+    let i = aliases.binary_search_by_key(&normalize(name).as_str(), |&(alias, _)| alias).ok()?;
+    Some(aliases[i].1)
+}
+
+fn table(table: &[(&str, Ranges)], name: &str) -> Ranges {
+    let i = table.binary_search_by_key(&name, |&(name, _)| name).unwrap();
+    table[i].1
+}
+
+fn in_ranges(ranges: Ranges, ch: char) -> bool {
+    ranges
+        .binary_search_by(|&(from, to)| {
+            if to < ch {
+                Ordering::Less
+            } else if from > ch {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+// \d, \w and \s as defined by UTS #18
+pub(crate) fn is_digit(ch: char) -> bool {
+    in_ranges(table(GENERAL_CATEGORY, "Nd"), ch)
+}
+
+pub(crate) fn is_word(ch: char) -> bool {
+    in_ranges(table(BINARY_PROPERTY, "Alphabetic"), ch)
+        || ["Mc", "Me", "Mn", "Nd", "Pc"]
+            .iter()
+            .any(|&category| in_ranges(table(GENERAL_CATEGORY, category), ch))
+        || in_ranges(table(BINARY_PROPERTY, "Join_Control"), ch)
+}
+
+pub(crate) fn is_space(ch: char) -> bool {
+    in_ranges(table(BINARY_PROPERTY, "White_Space"), ch)
+}
+
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| !matches!(c, ' ' | '_' | '-'))
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+// the other chars that are equal to `c` under simple case folding, e.g. 'K' and the Kelvin sign for 'k'
+pub(crate) fn case_orbit(c: char) -> &'static [char] {
+    match CASE_ORBITS.binary_search_by_key(&c, |&(c, _)| c) {
+        Ok(i) => CASE_ORBITS[i].1,
+        Err(_) => &[],
+    }
+}
+
+pub(crate) fn eq_ignore_case(a: char, b: char) -> bool {
+    a == b || case_orbit(a).contains(&b)
+}
+
+pub(crate) fn simple_fold(c: char) -> char {
+    match SIMPLE_CASE_FOLDING.binary_search_by_key(&c, |&(c, _)| c) {
+        Ok(i) => SIMPLE_CASE_FOLDING[i].1,
+        Err(_) => c,
+    }
+}
+
+pub(crate) fn has_full_fold(c: char) -> bool {
+    FULL_CASE_FOLDING.binary_search_by_key(&c, |&(c, _)| c).is_ok()
+}
+
+// appends the full case folding of `c` to `folded`, which may be several chars, e.g. "ss" for 'ß'
+pub(crate) fn full_fold(c: char, folded: &mut String) {
+    match FULL_CASE_FOLDING.binary_search_by_key(&c, |&(c, _)| c) {
+        Ok(i) => folded.push_str(FULL_CASE_FOLDING[i].1),
+        Err(_) => folded.push(simple_fold(c)),
+    }
+}
+
+// the byte length of the prefix of `text` whose full case folding is `folded`
+pub(crate) fn prefix_len_full_fold(text: &str, folded: &str) -> Option<usize> {
+    if folded.is_empty() {
+        return Some(0);
+    }
+    let mut folded_text = String::new();
+    for (i, ch) in text.char_indices() {
+        full_fold(ch, &mut folded_text);
+        if !folded.starts_with(&folded_text) {
+            break;
+        }
+        if folded_text.len() == folded.len() {
+            return Some(i + ch.len_utf8());
+        }
+    }
+    None
+}
+
+// whether a single char may fold to a part of `folded`, as 'ß' to the "ss" of "class"
+pub(crate) fn contains_full_fold(folded: &str) -> bool {
+    FULL_CASE_FOLDING.iter().any(|&(_, fold)| folded.contains(fold))
+}
+
+// the full case foldings longer than one char of the chars from `from` to `to`, or of the chars
+// equal to one of them under simple case folding
+pub(crate) fn full_folds_in(from: char, to: char) -> impl Iterator<Item = &'static str> {
+    let range = from..=to;
+    FULL_CASE_FOLDING
+        .iter()
+        .filter(move |&&(c, _)| range.contains(&c) || case_orbit(c).iter().any(|c| range.contains(c)))
+        .map(|&(_, fold)| fold)
+}
+
+// the byte length of the prefix of `text` that equals `prefix` under simple case folding
+pub(crate) fn prefix_len_ignore_case(text: &str, prefix: &str) -> Option<usize> {
+    let mut chars = text.char_indices();
+    let mut len = 0;
+    for c in prefix.chars() {
+        match chars.next() {
+            Some((i, ch)) if eq_ignore_case(c, ch) => len = i + ch.len_utf8(),
+            _ => return None,
+        }
+    }
+    Some(len)
+}
@@ -0,0 +1,4 @@
+pub(crate) mod binary_property;
+pub(crate) mod case_folding;
+pub(crate) mod general_category;
+pub(crate) mod script;